@@ -1,15 +1,25 @@
 use crate::{
     functions::{
+        branch::{get_branch, get_branch_completion},
+        clear::{get_clear, get_clear_completion},
+        export::{get_export, get_export_completion},
         follow::{get_follow, get_follow_completion},
         goto::{get_goto, get_goto_completion},
+        import::{get_import, get_import_completion},
+        list::{get_list, get_list_completion},
+        note::{get_note, get_note_completion},
         open::{get_open, get_open_completion},
+        pin::{get_pin, get_pin_completion},
         place::{get_place, get_place_completion},
         pop::{get_pop, get_pop_completion},
+        r#move::{get_move, get_move_completion},
+        restore::{get_restore, get_restore_completion},
         setup::get_setup,
+        slot::{get_slot, get_slot_completion},
         CommandNames,
     },
     state::Tracker,
-    viml::CompassArgs,
+    viml::{split_args, CompassArgs},
     InputError, Result,
 };
 use std::{str::FromStr, sync::Mutex};
@@ -48,6 +58,36 @@ pub fn init() -> Result<Dictionary> {
     let follow = get_follow(tracker);
     dict.insert("follow", Function::<_, Result<_>>::from_fn(follow));
 
+    let pin = get_pin(tracker);
+    dict.insert("pin", Function::<_, Result<_>>::from_fn(pin));
+
+    let note = get_note(tracker);
+    dict.insert("note", Function::<_, Result<_>>::from_fn(note));
+
+    let branch = get_branch(tracker);
+    dict.insert("branch", Function::<_, Result<_>>::from_fn(branch));
+
+    let list = get_list(tracker);
+    dict.insert("list", Function::<_, Result<_>>::from_fn(list));
+
+    let slot = get_slot(tracker);
+    dict.insert("slot", Function::<_, Result<_>>::from_fn(slot));
+
+    let restore = get_restore(tracker);
+    dict.insert("restore", Function::<_, Result<_>>::from_fn(restore));
+
+    let clear = get_clear(tracker);
+    dict.insert("clear", Function::<_, Result<_>>::from_fn(clear));
+
+    let r#move = get_move(tracker);
+    dict.insert("move", Function::<_, Result<_>>::from_fn(r#move));
+
+    let import = get_import(tracker);
+    dict.insert("import", Function::<_, Result<_>>::from_fn(import));
+
+    let export = get_export(tracker);
+    dict.insert("export", Function::<_, Result<_>>::from_fn(export));
+
     // Setting up `Compass COMMAND` user-commands
     user_commands(tracker)?;
 
@@ -60,10 +100,20 @@ fn user_commands(tracker: &'static Mutex<Tracker>) -> Result<()> {
     let open = get_open(tracker);
     let place = get_place(tracker);
     let follow = get_follow(tracker);
+    let pin = get_pin(tracker);
+    let note = get_note(tracker);
+    let branch = get_branch(tracker);
+    let list = get_list(tracker);
+    let slot = get_slot(tracker);
+    let restore = get_restore(tracker);
+    let clear = get_clear(tracker);
+    let r#move = get_move(tracker);
+    let import = get_import(tracker);
+    let export = get_export(tracker);
 
     let subcommands = move |ca: CommandArgs| -> Result<()> {
-        let cargs =
-            CompassArgs::try_from(ca.fargs.iter().map(AsRef::as_ref).collect::<Vec<&str>>())?;
+        let args = split_args(ca.args.as_deref().unwrap_or_default());
+        let cargs = CompassArgs::try_from(args.iter().map(AsRef::as_ref).collect::<Vec<&str>>())?;
 
         match CommandNames::from_str(cargs.main_cmd).map_err(|_| {
             InputError::FunctionArguments(
@@ -75,6 +125,16 @@ fn user_commands(tracker: &'static Mutex<Tracker>) -> Result<()> {
             CommandNames::Open => open(Some(cargs.try_into()?))?,
             CommandNames::Place => place(Some(cargs.try_into()?))?,
             CommandNames::Follow => follow(Some(cargs.try_into()?))?,
+            CommandNames::Pin => pin(Some(cargs.try_into()?))?,
+            CommandNames::Note => note(Some(cargs.try_into()?))?,
+            CommandNames::Branch => branch(Some(cargs.try_into()?))?,
+            CommandNames::List => list(Some(cargs.try_into()?))?,
+            CommandNames::Slot => slot(Some(cargs.try_into()?))?,
+            CommandNames::Restore => restore(Some(cargs.try_into()?))?,
+            CommandNames::Clear => clear(Some(cargs.try_into()?))?,
+            CommandNames::Move => r#move(Some(cargs.try_into()?))?,
+            CommandNames::Import => import(Some(cargs.try_into()?))?,
+            CommandNames::Export => export(Some(cargs.try_into()?))?,
         };
 
         Ok(())
@@ -93,7 +153,7 @@ fn user_commands(tracker: &'static Mutex<Tracker>) -> Result<()> {
         }),
         &CreateCommandOpts::builder()
             .nargs(CommandNArgs::OneOrMore)
-            .complete(cmd_completion())
+            .complete(cmd_completion(tracker))
             .build(),
     )?;
 
@@ -102,32 +162,44 @@ fn user_commands(tracker: &'static Mutex<Tracker>) -> Result<()> {
     Ok(())
 }
 
-fn cmd_completion() -> CommandComplete {
-    CommandComplete::CustomList(Function::from(|(_, full, _): (String, String, usize)| {
-        let full = full.replace("Compass", "");
-        let full: Vec<&str> = full.split_whitespace().collect();
+fn cmd_completion(tracker: &'static Mutex<Tracker>) -> CommandComplete {
+    CommandComplete::CustomList(Function::from(
+        move |(_, full, _): (String, String, usize)| {
+            let full = split_args(&full.replace("Compass", ""));
+            let full: Vec<&str> = full.iter().map(AsRef::as_ref).collect();
 
-        let Ok(cargs) = TryInto::<CompassArgs>::try_into(full) else {
-            return CommandNames::VARIANTS
-                .iter()
-                .map(|&s| s.to_owned())
-                .collect::<Vec<String>>();
-        };
-        let Ok(cmd) = CommandNames::from_str(cargs.main_cmd) else {
-            return CommandNames::VARIANTS
-                .iter()
-                .map(|&s| s.to_owned())
-                .collect::<Vec<String>>();
-        };
+            let Ok(cargs) = TryInto::<CompassArgs>::try_into(full) else {
+                return CommandNames::VARIANTS
+                    .iter()
+                    .map(|&s| s.to_owned())
+                    .collect::<Vec<String>>();
+            };
+            let Ok(cmd) = CommandNames::from_str(cargs.main_cmd) else {
+                return CommandNames::VARIANTS
+                    .iter()
+                    .map(|&s| s.to_owned())
+                    .collect::<Vec<String>>();
+            };
 
-        match cmd {
-            CommandNames::Goto => get_goto_completion(&cargs),
-            CommandNames::Pop => get_pop_completion(&cargs),
-            CommandNames::Open => get_open_completion(),
-            CommandNames::Place => get_place_completion(&cargs),
-            CommandNames::Follow => get_follow_completion(&cargs),
-        }
-    }))
+            match cmd {
+                CommandNames::Goto => get_goto_completion(&cargs, tracker),
+                CommandNames::Pop => get_pop_completion(&cargs),
+                CommandNames::Open => get_open_completion(&cargs),
+                CommandNames::Place => get_place_completion(&cargs),
+                CommandNames::Follow => get_follow_completion(&cargs),
+                CommandNames::Pin => get_pin_completion(&cargs),
+                CommandNames::Note => get_note_completion(&cargs),
+                CommandNames::Branch => get_branch_completion(&cargs),
+                CommandNames::List => get_list_completion(&cargs, tracker),
+                CommandNames::Slot => get_slot_completion(&cargs, tracker),
+                CommandNames::Restore => get_restore_completion(&cargs),
+                CommandNames::Clear => get_clear_completion(&cargs),
+                CommandNames::Move => get_move_completion(&cargs),
+                CommandNames::Import => get_import_completion(&cargs),
+                CommandNames::Export => get_export_completion(&cargs),
+            }
+        },
+    ))
 }
 
 fn plug_keymaps() -> Result<()> {
@@ -141,26 +213,26 @@ fn plug_keymaps() -> Result<()> {
     set_keymap(
         Mode::Normal,
         "<Plug>(CompassGotoBack)",
-        ":Compass goto relative direction=back<CR>",
+        ":<C-u>execute 'Compass goto relative direction=back count=' . v:count1<CR>",
         &SetKeymapOpts::builder().noremap(true).build(),
     )?;
     set_keymap(
         Mode::Normal,
         "<Plug>(CompassGotoForward)",
-        ":Compass goto relative direction=forward<CR>",
+        ":<C-u>execute 'Compass goto relative direction=forward count=' . v:count1<CR>",
         &SetKeymapOpts::builder().noremap(true).build(),
     )?;
 
     set_keymap(
         Mode::Normal,
         "<Plug>(CompassPopBack)",
-        ":Compass pop relative direction=back<CR>",
+        ":<C-u>execute 'Compass pop relative direction=back count=' . v:count1<CR>",
         &SetKeymapOpts::builder().noremap(true).build(),
     )?;
     set_keymap(
         Mode::Normal,
         "<Plug>(CompassPopForward)",
-        ":Compass pop relative direction=forward<CR>",
+        ":<C-u>execute 'Compass pop relative direction=forward count=' . v:count1<CR>",
         &SetKeymapOpts::builder().noremap(true).build(),
     )?;
 
@@ -170,6 +242,26 @@ fn plug_keymaps() -> Result<()> {
         ":Compass place change<CR>",
         &SetKeymapOpts::builder().noremap(true).build(),
     )?;
+    set_keymap(
+        Mode::Normal,
+        "<Plug>(CompassPlacePin)",
+        ":Compass place pin<CR>",
+        &SetKeymapOpts::builder().noremap(true).build(),
+    )?;
+
+    set_keymap(
+        Mode::Normal,
+        "<Plug>(CompassPinToggle)",
+        ":Compass pin toggle<CR>",
+        &SetKeymapOpts::builder().noremap(true).build(),
+    )?;
+
+    set_keymap(
+        Mode::Normal,
+        "<Plug>(CompassBranchSwitch)",
+        ":Compass branch switch<CR>",
+        &SetKeymapOpts::builder().noremap(true).build(),
+    )?;
 
     Ok(())
 }
@@ -57,6 +57,12 @@ impl CursorPosition {
 
         near_line && near_col
     }
+
+    /// Line and column as taken by `nvim_buf_set_mark()` and returned by `nvim_get_mark()`,
+    /// which are 1,0 indexed just like the position, unlike the 0,0 indexed extmarks
+    pub fn mark_pos(&self) -> (usize, usize) {
+        (self.line, self.col)
+    }
 }
 
 impl From<(usize, usize)> for CursorPosition {
@@ -116,4 +122,35 @@ mod tests {
 
         assert!(pos1.is_nearby(&pos2))
     }
+
+    #[nvim_oxi::test]
+    fn mark_pos_points_at_extmark_pos() {
+        use crate::{common_types::Extmark, ui::namespace::get_namespace};
+        use nvim_oxi::api::{
+            get_current_buf, get_mark,
+            opts::{GetMarkOpts, SetExtmarkOpts, SetMarkOpts},
+        };
+
+        let mut buf = get_current_buf();
+        buf.set_lines(.., true, ["first", "second", "third line"])
+            .unwrap();
+        let pos: CursorPosition = (3, 4).into();
+        let ran: CursorRange = (&pos).into();
+        let id = buf
+            .set_extmark(
+                get_namespace().into(),
+                ran.line,
+                ran.col,
+                &SetExtmarkOpts::builder().build(),
+            )
+            .unwrap();
+
+        let (line, col) = pos.mark_pos();
+        buf.set_mark('Z', line, col, &SetMarkOpts::builder().build())
+            .unwrap();
+
+        let (line, col, _, _) = get_mark('Z', &GetMarkOpts::builder().build()).unwrap();
+        let extmark = Extmark::try_new(id, buf.clone()).unwrap();
+        assert_eq!(CursorPosition::from((line, col)), extmark.pos(buf));
+    }
 }
@@ -9,3 +9,12 @@ pub enum Direction {
     Back,
     Forward,
 }
+
+#[derive(Default, Clone, Copy, PartialEq, Eq, Debug, Deserialize, EnumString)]
+#[serde(rename_all = "snake_case")]
+#[strum(serialize_all = "snake_case")]
+pub enum VerticalDirection {
+    #[default]
+    Down,
+    Up,
+}
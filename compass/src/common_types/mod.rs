@@ -7,6 +7,12 @@ pub use cursor_position::*;
 pub mod direction;
 pub use direction::*;
 
+mod scope;
+pub use scope::*;
+
+mod unit;
+pub use unit::*;
+
 mod extmark;
 pub use extmark::*;
 
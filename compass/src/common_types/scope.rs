@@ -0,0 +1,15 @@
+use serde::Deserialize;
+use strum_macros::EnumString;
+
+#[derive(Default, Clone, Copy, PartialEq, Eq, Debug, Deserialize, EnumString)]
+#[serde(rename_all = "snake_case")]
+#[strum(serialize_all = "snake_case")]
+pub enum Scope {
+    #[default]
+    Global,
+    /// Only records in the current buffer
+    Buf,
+    /// Only records created in the current window,
+    /// or in the current buffer for the ones whose window is unknown, like after a restart
+    Win,
+}
@@ -1,7 +1,7 @@
 use std::fmt::Display;
 
 use bitcode::{Decode, Encode};
-use chrono::{DateTime, Utc};
+use chrono::{DateTime, NaiveTime, TimeDelta, TimeZone, Utc};
 use serde::de;
 
 #[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, Decode, Encode)]
@@ -35,6 +35,65 @@ impl<'de> de::Deserialize<'de> for Timestamp {
     }
 }
 
+impl Timestamp {
+    /// Parses relative expressions like `10m ago` or `1h 30min ago`
+    /// and clock times like `14:30`, the ones later than now refer to the previous day
+    pub fn parse_human<Tz: TimeZone>(s: &str, now: DateTime<Tz>) -> Option<Self> {
+        let s = s.trim();
+        if let Some(seconds) = parse_duration(s) {
+            return Some(Self(now.timestamp() - seconds));
+        }
+
+        let time = NaiveTime::parse_from_str(s, "%H:%M")
+            .or_else(|_| NaiveTime::parse_from_str(s, "%H:%M:%S"))
+            .ok()?;
+        let mut moment = now
+            .date_naive()
+            .and_time(time)
+            .and_local_timezone(now.timezone())
+            .earliest()?;
+        if moment > now {
+            moment = moment.checked_sub_signed(TimeDelta::days(1))?;
+        }
+
+        Some(Self(moment.timestamp()))
+    }
+}
+
+/// Returns the number of seconds described by a sequence of amounts with units like `1h 30m`,
+/// optionally followed by `ago`
+pub fn parse_duration(s: &str) -> Option<i64> {
+    let mut rest = s.strip_suffix("ago").unwrap_or(s).trim();
+    if rest.is_empty() {
+        return None;
+    }
+
+    let mut seconds: i64 = 0;
+    while !rest.is_empty() {
+        let end_amount = rest
+            .find(|c: char| !c.is_ascii_digit())
+            .unwrap_or(rest.len());
+        let amount: i64 = rest[..end_amount].parse().ok()?;
+        rest = rest[end_amount..].trim_start();
+
+        let end_unit = rest
+            .find(|c: char| !c.is_alphabetic())
+            .unwrap_or(rest.len());
+        let unit: i64 = match &rest[..end_unit] {
+            "s" | "sec" | "secs" | "second" | "seconds" => 1,
+            "m" | "min" | "mins" | "minute" | "minutes" => 60,
+            "h" | "hr" | "hrs" | "hour" | "hours" => 60 * 60,
+            "d" | "day" | "days" => 24 * 60 * 60,
+            _ => return None,
+        };
+        rest = rest[end_unit..].trim_start();
+
+        seconds = seconds.checked_add(amount.checked_mul(unit)?)?;
+    }
+
+    Some(seconds)
+}
+
 impl Display for Timestamp {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         write!(f, "{}", self.0)
@@ -47,6 +106,12 @@ impl From<DateTime<Utc>> for Timestamp {
     }
 }
 
+impl From<i64> for Timestamp {
+    fn from(value: i64) -> Self {
+        Self(value)
+    }
+}
+
 impl From<Timestamp> for i64 {
     fn from(value: Timestamp) -> Self {
         value.0
@@ -58,3 +123,38 @@ impl From<Timestamp> for DateTime<Utc> {
         DateTime::from_timestamp(value.into(), 0).unwrap()
     }
 }
+
+mod tests {
+    use super::*;
+
+    #[test]
+    fn can_parse_relative_time() {
+        // 2024-06-01 12:00:00 UTC
+        let now = DateTime::from_timestamp(1_717_243_200, 0).unwrap();
+        assert_eq!(
+            Timestamp::parse_human("10m ago", now),
+            Some(Timestamp(1_717_243_200 - 10 * 60))
+        );
+        assert_eq!(
+            Timestamp::parse_human("1h 30min ago", now),
+            Some(Timestamp(1_717_243_200 - 90 * 60))
+        );
+        assert_eq!(Timestamp::parse_human("10 parsecs ago", now), None);
+        assert_eq!(Timestamp::parse_human("ago", now), None);
+    }
+
+    #[test]
+    fn can_parse_clock_time() {
+        // 2024-06-01 12:00:00 UTC
+        let now = DateTime::from_timestamp(1_717_243_200, 0).unwrap();
+        assert_eq!(
+            Timestamp::parse_human("11:30", now),
+            Some(Timestamp(1_717_243_200 - 30 * 60))
+        );
+        // Not reached yet today, so it is yesterday's
+        assert_eq!(
+            Timestamp::parse_human("14:00:00", now),
+            Some(Timestamp(1_717_243_200 - 22 * 60 * 60))
+        );
+    }
+}
@@ -0,0 +1,15 @@
+use serde::Deserialize;
+use strum_macros::EnumString;
+
+/// Granularity of a relative move
+#[derive(Default, Clone, Copy, PartialEq, Eq, Debug, Deserialize, EnumString)]
+#[serde(rename_all = "snake_case")]
+#[strum(serialize_all = "snake_case")]
+pub enum Unit {
+    #[default]
+    Record,
+    /// Skips the records in the same buffer as the one we move from
+    File,
+    /// Jumps to the start of a work session
+    Session,
+}
@@ -13,6 +13,9 @@ pub struct Signs {
     pub future: SignText,
     #[serde(default = "default_future")]
     pub close_future: SignText,
+
+    #[serde(default = "default_pinned")]
+    pub pinned: SignText,
 }
 
 fn default_past() -> SignText {
@@ -23,6 +26,10 @@ fn default_future() -> SignText {
     "▶".to_owned().into()
 }
 
+fn default_pinned() -> SignText {
+    "●".to_owned().into()
+}
+
 impl Default for Signs {
     fn default() -> Self {
         Self {
@@ -30,6 +37,7 @@ impl Default for Signs {
             close_past: default_past(),
             future: default_future(),
             close_future: default_future(),
+            pinned: default_pinned(),
         }
     }
 }
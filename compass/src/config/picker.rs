@@ -9,11 +9,38 @@ pub use jump_keymaps::*;
 
 use serde::de;
 
-#[derive(Debug, Default)]
+#[derive(Debug)]
 pub struct PickerConfig {
     pub max_windows: WindowGridSize,
     pub jump_keys: JumpKeymapList,
     pub filename: Filename,
+    /// Modifier held together with the key of a hint standing for a single record to delete it instead of jumping
+    pub delete_modifier: String,
+}
+
+fn default_delete_modifier() -> String {
+    "M".to_owned()
+}
+
+/// Modifiers vim accepts in `<X-key>` notation, possibly combined like `C-S`
+fn is_valid_modifier(modifier: &str) -> bool {
+    const MODIFIERS: &[&str] = &["S", "C", "M", "A", "D", "T"];
+
+    !modifier.is_empty()
+        && modifier
+            .split('-')
+            .all(|m| MODIFIERS.iter().any(|v| v.eq_ignore_ascii_case(m)))
+}
+
+impl Default for PickerConfig {
+    fn default() -> Self {
+        Self {
+            max_windows: WindowGridSize::default(),
+            jump_keys: JumpKeymapList::default(),
+            filename: Filename::default(),
+            delete_modifier: default_delete_modifier(),
+        }
+    }
 }
 
 impl<'de> de::Deserialize<'de> for PickerConfig {
@@ -25,6 +52,7 @@ impl<'de> de::Deserialize<'de> for PickerConfig {
             MaxWindows,
             JumpKeys,
             Filename,
+            DeleteModifier,
         }
         impl<'de> de::Deserialize<'de> for Field {
             fn deserialize<D>(deserializer: D) -> Result<Field, D::Error>
@@ -37,7 +65,9 @@ impl<'de> de::Deserialize<'de> for PickerConfig {
                     type Value = Field;
 
                     fn expecting(&self, formatter: &mut std::fmt::Formatter) -> std::fmt::Result {
-                        formatter.write_str("`max_windows`, `jump_keys`, or `filename`")
+                        formatter.write_str(
+                            "`max_windows`, `jump_keys`, `filename`, or `delete_modifier`",
+                        )
                     }
 
                     fn visit_str<E>(self, value: &str) -> Result<Field, E>
@@ -48,6 +78,7 @@ impl<'de> de::Deserialize<'de> for PickerConfig {
                             "max_windows" => Ok(Field::MaxWindows),
                             "jump_keys" => Ok(Field::JumpKeys),
                             "filename" => Ok(Field::Filename),
+                            "delete_modifier" => Ok(Field::DeleteModifier),
                             _ => Err(de::Error::unknown_field(value, FIELDS)),
                         }
                     }
@@ -73,6 +104,7 @@ impl<'de> de::Deserialize<'de> for PickerConfig {
                 let mut max_windows = None;
                 let mut jump_keys = None;
                 let mut filename = None;
+                let mut delete_modifier = None;
 
                 while let Some(key) = map.next_key()? {
                     match key {
@@ -94,6 +126,12 @@ impl<'de> de::Deserialize<'de> for PickerConfig {
                             }
                             filename = Some(map.next_value()?);
                         }
+                        Field::DeleteModifier => {
+                            if delete_modifier.is_some() {
+                                return Err(de::Error::duplicate_field("delete_modifier"));
+                            }
+                            delete_modifier = Some(map.next_value()?);
+                        }
                     }
                 }
 
@@ -107,16 +145,38 @@ impl<'de> de::Deserialize<'de> for PickerConfig {
                 };
 
                 let filename = filename.unwrap_or_default();
+                let delete_modifier: String =
+                    delete_modifier.unwrap_or_else(default_delete_modifier);
+                if !is_valid_modifier(&delete_modifier) {
+                    return Err(de::Error::invalid_value(
+                        de::Unexpected::Str(&delete_modifier),
+                        &"a key modifier such as \"M\", \"C\" or \"C-S\"",
+                    ));
+                };
 
                 Ok(PickerConfig {
                     max_windows,
                     jump_keys,
                     filename,
+                    delete_modifier,
                 })
             }
         }
 
-        const FIELDS: &[&str] = &["max_windows", "jump_keys", "filename"];
+        const FIELDS: &[&str] = &["max_windows", "jump_keys", "filename", "delete_modifier"];
         deserializer.deserialize_struct("PickerConfig", FIELDS, PickerConfigVisitor)
     }
 }
+
+mod tests {
+    use super::*;
+
+    #[test]
+    fn validates_delete_modifier() {
+        assert!(is_valid_modifier("M"));
+        assert!(is_valid_modifier("c-s"));
+        assert!(!is_valid_modifier(""));
+        assert!(!is_valid_modifier("CR"));
+        assert!(!is_valid_modifier("C-"));
+    }
+}
@@ -1,3 +1,21 @@
+mod history;
+pub use history::*;
+
+mod stale;
+pub use stale::*;
+
+mod work_sessions;
+pub use work_sessions::*;
+
+mod jumplist;
+pub use jumplist::*;
+
+mod seed;
+pub use seed::*;
+
+mod global_marks;
+pub use global_marks::*;
+
 use std::time::Duration;
 
 use globset::{Glob, GlobSet};
@@ -10,6 +28,24 @@ pub struct TrackerConfig {
 
     #[serde(default = "default_ignored_patterns")]
     pub ignored_patterns: GlobSet,
+
+    #[serde(default)]
+    pub history: HistoryConfig,
+
+    #[serde(default)]
+    pub stale: StaleConfig,
+
+    #[serde(default)]
+    pub work_sessions: WorkSessionsConfig,
+
+    #[serde(default)]
+    pub jumplist: JumplistConfig,
+
+    #[serde(default)]
+    pub seed: SeedConfig,
+
+    #[serde(default)]
+    pub global_marks: GlobalMarksConfig,
 }
 
 fn default_ignored_patterns() -> GlobSet {
@@ -70,6 +106,12 @@ impl Default for TrackerConfig {
         Self {
             debounce_milliseconds: Debounce::default(),
             ignored_patterns: default_ignored_patterns(),
+            history: HistoryConfig::default(),
+            stale: StaleConfig::default(),
+            work_sessions: WorkSessionsConfig::default(),
+            jumplist: JumplistConfig::default(),
+            seed: SeedConfig::default(),
+            global_marks: GlobalMarksConfig::default(),
         }
     }
 }
@@ -0,0 +1,39 @@
+use serde::{de, Deserialize, Deserializer};
+
+#[derive(Debug, Deserialize)]
+pub struct GlobalMarksConfig {
+    /// Whether to keep the global marks pointed at the most frecent records
+    #[serde(default)]
+    pub enable: bool,
+    /// Marks to use, the first one is given to the most frecent record
+    #[serde(default = "default_names", deserialize_with = "deserialize_names")]
+    pub names: Vec<char>,
+}
+
+fn default_names() -> Vec<char> {
+    Vec::from(['A', 'B', 'C'])
+}
+
+fn deserialize_names<'de, D>(deserializer: D) -> Result<Vec<char>, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    let names = Vec::<char>::deserialize(deserializer)?;
+    if let Some(c) = names.iter().find(|c| !c.is_ascii_uppercase()) {
+        return Err(de::Error::custom(format!(
+            "global mark name has to be an uppercase letter, got: {}",
+            c
+        )));
+    }
+
+    Ok(names)
+}
+
+impl Default for GlobalMarksConfig {
+    fn default() -> Self {
+        Self {
+            enable: false,
+            names: default_names(),
+        }
+    }
+}
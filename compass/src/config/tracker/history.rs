@@ -0,0 +1,99 @@
+use chrono::TimeDelta;
+use serde::{de, Deserialize, Deserializer};
+
+#[derive(Debug, Deserialize)]
+pub struct HistoryConfig {
+    #[serde(default)]
+    pub max_records: Option<usize>,
+    #[serde(default)]
+    pub max_records_per_buf: Option<usize>,
+    #[serde(default, deserialize_with = "deserialize_max_age_days")]
+    pub max_age_days: Option<u32>,
+    #[serde(default)]
+    pub eviction: EvictionPolicy,
+    /// Whether to start a new branch instead of inserting when recording while in the past
+    #[serde(default)]
+    pub branching: bool,
+    /// Whether every window or tab moves through the history with its own position
+    #[serde(default)]
+    pub position_scope: PositionScope,
+    /// Number of popped records kept around to be restored
+    #[serde(default = "default_trash_size")]
+    pub trash_size: usize,
+}
+
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum PositionScope {
+    /// A single position shared by all windows
+    #[default]
+    Global,
+    /// Every window has its own position, similar to the jumplist
+    Window,
+    /// Windows of the same tab share a position
+    Tab,
+}
+
+/// Decides which records are the first to go once a limit is exceeded
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum EvictionPolicy {
+    /// Lowest frecency score
+    #[default]
+    Frecency,
+    /// Earliest created
+    Oldest,
+    /// Least recently used
+    Lru,
+}
+
+fn deserialize_max_age_days<'de, D>(deserializer: D) -> Result<Option<u32>, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    let days = Option::<u32>::deserialize(deserializer)?;
+    if let Some(d) = days.filter(|&d| d == 0 || TimeDelta::try_days(d.into()).is_none()) {
+        return Err(de::Error::custom(format!(
+            "max_age_days has to be a positive number of days within range, got: {}",
+            d
+        )));
+    }
+
+    Ok(days)
+}
+
+fn default_trash_size() -> usize {
+    20
+}
+
+impl Default for HistoryConfig {
+    fn default() -> Self {
+        Self {
+            max_records: None,
+            max_records_per_buf: None,
+            max_age_days: None,
+            eviction: EvictionPolicy::default(),
+            branching: false,
+            position_scope: PositionScope::default(),
+            trash_size: default_trash_size(),
+        }
+    }
+}
+
+mod tests {
+    use super::*;
+
+    use serde_json::json;
+
+    #[test]
+    fn validates_max_age_days() {
+        assert_eq!(
+            HistoryConfig::deserialize(json!({ "max_age_days": 7 }))
+                .unwrap()
+                .max_age_days,
+            Some(7)
+        );
+        assert!(HistoryConfig::deserialize(json!({ "max_age_days": 0 })).is_err());
+        assert!(HistoryConfig::deserialize(json!({ "max_age_days": -1 })).is_err());
+    }
+}
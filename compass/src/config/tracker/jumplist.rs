@@ -0,0 +1,24 @@
+use serde::Deserialize;
+
+#[derive(Debug, Deserialize)]
+pub struct JumplistConfig {
+    /// Whether jumping to a record leaves an entry in the jumplist for `<C-o>` to return to
+    #[serde(default = "default_leave_entry")]
+    pub leave_entry: bool,
+    /// Whether consecutive steps through the records leave only the entry of the first one
+    #[serde(default)]
+    pub collapse_steps: bool,
+}
+
+fn default_leave_entry() -> bool {
+    true
+}
+
+impl Default for JumplistConfig {
+    fn default() -> Self {
+        Self {
+            leave_entry: default_leave_entry(),
+            collapse_steps: false,
+        }
+    }
+}
@@ -0,0 +1,34 @@
+use serde::Deserialize;
+
+#[derive(Debug, Deserialize)]
+pub struct SeedConfig {
+    /// Whether to create records out of vim's own lists when visiting a buffer without any
+    #[serde(default)]
+    pub enable: bool,
+    #[serde(default = "default_true")]
+    pub changelist: bool,
+    #[serde(default = "default_true")]
+    pub marks: bool,
+    /// Maximal number of the most recent changes taken from the changelist
+    #[serde(default = "default_max_changes")]
+    pub max_changes: usize,
+}
+
+fn default_true() -> bool {
+    true
+}
+
+fn default_max_changes() -> usize {
+    10
+}
+
+impl Default for SeedConfig {
+    fn default() -> Self {
+        Self {
+            enable: false,
+            changelist: true,
+            marks: true,
+            max_changes: default_max_changes(),
+        }
+    }
+}
@@ -0,0 +1,49 @@
+use chrono::TimeDelta;
+use serde::{de, Deserialize, Deserializer};
+
+#[derive(Debug, Default, Deserialize)]
+pub struct StaleConfig {
+    #[serde(default)]
+    pub enable: bool,
+    #[serde(default, deserialize_with = "deserialize_evict_after_seconds")]
+    pub evict_after_seconds: Option<u64>,
+}
+
+fn deserialize_evict_after_seconds<'de, D>(deserializer: D) -> Result<Option<u64>, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    let seconds = Option::<u64>::deserialize(deserializer)?;
+    if let Some(s) = seconds.filter(|&s| to_time_delta(s).is_none()) {
+        return Err(de::Error::custom(format!(
+            "evict_after_seconds is out of range, got: {}",
+            s
+        )));
+    }
+
+    Ok(seconds)
+}
+
+/// Duration of the seconds, `None` when it can not be represented
+pub fn to_time_delta(seconds: u64) -> Option<TimeDelta> {
+    TimeDelta::try_seconds(seconds.try_into().ok()?)
+}
+
+mod tests {
+    use super::*;
+
+    use serde_json::json;
+
+    #[test]
+    fn validates_evict_after_seconds() {
+        assert!(!StaleConfig::default().enable);
+        assert_eq!(
+            StaleConfig::deserialize(json!({ "evict_after_seconds": 60 }))
+                .unwrap()
+                .evict_after_seconds,
+            Some(60)
+        );
+        assert!(StaleConfig::deserialize(json!({ "evict_after_seconds": -1 })).is_err());
+        assert!(StaleConfig::deserialize(json!({ "evict_after_seconds": u64::MAX })).is_err());
+    }
+}
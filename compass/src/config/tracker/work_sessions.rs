@@ -0,0 +1,20 @@
+use serde::Deserialize;
+
+#[derive(Debug, Deserialize)]
+pub struct WorkSessionsConfig {
+    /// Time without any activity after which the following records start a new work session
+    #[serde(default = "default_idle_gap_minutes")]
+    pub idle_gap_minutes: i64,
+}
+
+fn default_idle_gap_minutes() -> i64 {
+    30
+}
+
+impl Default for WorkSessionsConfig {
+    fn default() -> Self {
+        Self {
+            idle_gap_minutes: default_idle_gap_minutes(),
+        }
+    }
+}
@@ -0,0 +1,13 @@
+use crate::viml::CompassArgs;
+
+pub fn get_branch_completion(cargs: &CompassArgs) -> Vec<String> {
+    let Some(first) = cargs.sub_cmds.first() else {
+        return Vec::from(&["switch".to_owned()]);
+    };
+
+    match *first {
+        "switch" => Vec::from(&["index=".to_owned()]),
+
+        _ => Vec::from(&["switch".to_owned()]),
+    }
+}
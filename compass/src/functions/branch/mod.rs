@@ -0,0 +1,33 @@
+mod completion;
+use std::sync::Mutex;
+
+pub use completion::*;
+
+mod opts;
+use opts::*;
+
+use crate::{state::Tracker, InputError, Result};
+
+pub fn get_branch(
+    tracker: &'static Mutex<Tracker>,
+) -> impl Fn(Option<BranchOptions>) -> Result<()> {
+    move |opts: Option<BranchOptions>| {
+        let opts = opts.unwrap_or_default();
+
+        let mut tracker = tracker.lock()?;
+
+        match opts {
+            BranchOptions::Switch(SwitchOptions { index }) => {
+                if tracker.list.branches.is_empty() {
+                    Err(InputError::NoRecords(
+                        "there are no branches to switch to".to_owned(),
+                    ))?
+                }
+
+                // Branches left in place by the previous switch come first, followed by the line
+                // we switched away from, so without an index we go back to it when there are none
+                tracker.switch_branch(index.unwrap_or(0))
+            }
+        }
+    }
+}
@@ -0,0 +1,53 @@
+use crate::{viml::CompassArgs, Error, InputError, Result};
+use macros::FromLua;
+
+use serde::Deserialize;
+
+#[derive(Deserialize, FromLua)]
+#[serde(rename_all = "snake_case")]
+pub enum BranchOptions {
+    Switch(SwitchOptions),
+}
+
+impl Default for BranchOptions {
+    fn default() -> Self {
+        BranchOptions::Switch(SwitchOptions::default())
+    }
+}
+
+#[derive(Default, Deserialize)]
+pub struct SwitchOptions {
+    /// Takes the first branch when not provided
+    #[serde(default)]
+    pub index: Option<usize>,
+}
+
+impl<'a> TryFrom<CompassArgs<'a>> for BranchOptions {
+    type Error = Error;
+
+    fn try_from(value: CompassArgs<'a>) -> Result<Self> {
+        let Some(&sub) = value.sub_cmds.first() else {
+            Err(InputError::FunctionArguments(
+                "no `branch` subcommand provided".to_owned(),
+            ))?
+        };
+
+        match sub {
+            "switch" => {
+                let index = value
+                    .map_args
+                    .get("index")
+                    .map(|s| s.parse::<usize>())
+                    .transpose()
+                    .map_err(InputError::Int)?;
+
+                Ok(Self::Switch(SwitchOptions { index }))
+            }
+
+            sub => Err(InputError::FunctionArguments(format!(
+                "unknown `branch` subcommand provided: {}",
+                sub
+            )))?,
+        }
+    }
+}
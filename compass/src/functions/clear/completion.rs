@@ -0,0 +1,33 @@
+use crate::viml::CompassArgs;
+
+pub fn get_clear_completion(cargs: &CompassArgs) -> Vec<String> {
+    let subcommands = || {
+        Vec::from(&[
+            "buf".to_owned(),
+            "all".to_owned(),
+            "older".to_owned(),
+            "frecency".to_owned(),
+            "glob".to_owned(),
+            "type".to_owned(),
+        ])
+    };
+
+    let Some(first) = cargs.sub_cmds.first() else {
+        return subcommands();
+    };
+
+    match *first {
+        "buf" | "all" => Vec::new(),
+        "older" => Vec::from(&["than=".to_owned()]),
+        "frecency" => Vec::from(&["below=".to_owned()]),
+        "glob" => Vec::from(&["pattern=".to_owned()]),
+        "type" => Vec::from(&[
+            "type=change".to_owned(),
+            "type=bookmark".to_owned(),
+            "type=jump".to_owned(),
+            "type=quickfix".to_owned(),
+        ]),
+
+        _ => subcommands(),
+    }
+}
@@ -0,0 +1,140 @@
+mod completion;
+use std::sync::Mutex;
+
+pub use completion::*;
+
+mod opts;
+use opts::*;
+
+use crate::{
+    common_types::parse_duration,
+    functions::open::RecordFilter,
+    state::{frecency::FrecencyScore, Tracker},
+    InputError, Result,
+};
+
+use chrono::Utc;
+use globset::Glob;
+use nvim_oxi::api::get_current_buf;
+
+pub fn get_clear(tracker: &'static Mutex<Tracker>) -> impl Fn(Option<ClearOptions>) -> Result<()> {
+    move |opts: Option<ClearOptions>| {
+        // Clearing is destructive, so it never falls back to a default scope
+        let Some(opts) = opts else {
+            Err(InputError::FunctionArguments(
+                "no `clear` scope provided".to_owned(),
+            ))?
+        };
+
+        let mut tracker = tracker.lock()?;
+
+        match opts {
+            ClearOptions::Buf(BufOptions {}) => {
+                let buf = get_current_buf();
+                tracker.clear(|r| r.buf == buf)
+            }
+
+            ClearOptions::All(AllOptions {}) => tracker.clear(|_| true),
+
+            ClearOptions::Older(OlderOptions { than }) => {
+                let seconds = parse_duration(&than).ok_or_else(|| {
+                    InputError::FunctionArguments(format!(
+                        "duration has to look like `7d` or `1h 30m`, got: {}",
+                        than
+                    ))
+                })?;
+                let cutoff = Utc::now().timestamp().checked_sub(seconds).ok_or_else(|| {
+                    InputError::FunctionArguments(format!("duration is too long: {}", than))
+                })?;
+                tracker.clear(|r| Into::<i64>::into(r.frecency.latest_timestamp()) < cutoff)
+            }
+
+            ClearOptions::Frecency(FrecencyOptions { below }) => {
+                tracker.clear(|r| r.frecency.total_score() < below)
+            }
+
+            ClearOptions::Glob(GlobOptions { pattern }) => {
+                let matcher = Glob::new(&pattern)
+                    .map_err(|e| InputError::FunctionArguments(e.to_string()))?
+                    .compile_matcher();
+                tracker.clear(|r| r.buf.get_name().is_ok_and(|n| matcher.is_match(n)))
+            }
+
+            ClearOptions::Type(TypeOptions { record_type }) => {
+                tracker.clear(|r| RecordFilter::from(&r.place_type) == record_type)
+            }
+        }
+    }
+}
+
+mod tests {
+    use std::sync::Mutex;
+
+    use crate::{
+        common_types::CursorPosition,
+        state::{ChangeTypeRecord, PlaceTypeRecord, Record},
+    };
+
+    use super::*;
+
+    use nvim_oxi::api::Buffer;
+
+    #[nvim_oxi::test]
+    fn requires_clear_scope() {
+        let tracker = Box::leak(Box::new(Mutex::new(Tracker::default())));
+        let clear = get_clear(tracker);
+
+        assert!(clear(None).is_err());
+    }
+
+    #[nvim_oxi::test]
+    fn clears_records_in_branches() {
+        let mut tracker = Tracker::default();
+        for i in 0..3 {
+            tracker.list.push(
+                Record::try_new(
+                    Buffer::current(),
+                    PlaceTypeRecord::Change(ChangeTypeRecord::Tick(i.into())),
+                    &CursorPosition::from((1, 0)),
+                )
+                .unwrap(),
+            );
+        }
+        tracker.list.pos = Some(0);
+        tracker.list.fork();
+        let tracker: &'static Mutex<Tracker> = Box::leak(Box::new(Mutex::new(tracker)));
+        let clear = get_clear(tracker);
+
+        clear(Some(ClearOptions::All(AllOptions {}))).unwrap();
+
+        let list = &tracker.lock().unwrap().list;
+        assert!(list.is_empty());
+        assert_eq!(list.iter_branches().count(), 0);
+    }
+
+    #[nvim_oxi::test]
+    fn keeps_pinned_and_slotted_records() {
+        let mut tracker = Tracker::default();
+        for i in 0..3 {
+            tracker.list.push(
+                Record::try_new(
+                    Buffer::current(),
+                    PlaceTypeRecord::Change(ChangeTypeRecord::Tick(i.into())),
+                    &CursorPosition::from((1, 0)),
+                )
+                .unwrap(),
+            );
+        }
+        tracker.list.get_mut(0).unwrap().pinned = true;
+        tracker.list.get_mut(1).unwrap().slot = Some(1);
+        let tracker: &'static Mutex<Tracker> = Box::leak(Box::new(Mutex::new(tracker)));
+        let clear = get_clear(tracker);
+
+        clear(Some(ClearOptions::All(AllOptions {}))).unwrap();
+
+        let list = &tracker.lock().unwrap().list;
+        assert_eq!(list.len(), 2);
+        assert!(list.get(0).unwrap().pinned);
+        assert_eq!(list.get(1).unwrap().slot, Some(1));
+    }
+}
@@ -0,0 +1,133 @@
+use crate::{
+    functions::open::RecordFilter, state::frecency::FrecencyWeight, viml::CompassArgs, Error,
+    InputError, Result,
+};
+use macros::FromLuaRequired;
+
+use serde::Deserialize;
+
+#[derive(Deserialize, FromLuaRequired)]
+#[serde(rename_all = "snake_case")]
+pub enum ClearOptions {
+    /// Records in the current buffer
+    Buf(BufOptions),
+    All(AllOptions),
+    /// Records not visited for longer than the duration
+    Older(OlderOptions),
+    /// Records with the frecency score below the value
+    Frecency(FrecencyOptions),
+    /// Records whose file paths match the glob
+    Glob(GlobOptions),
+    /// Records of the place type
+    Type(TypeOptions),
+}
+
+#[derive(Default, Deserialize)]
+pub struct BufOptions {}
+
+#[derive(Default, Deserialize)]
+pub struct AllOptions {}
+
+#[derive(Deserialize)]
+pub struct OlderOptions {
+    /// Duration like `7d` or `1h 30m`
+    pub than: String,
+}
+
+#[derive(Deserialize)]
+pub struct FrecencyOptions {
+    pub below: FrecencyWeight,
+}
+
+#[derive(Deserialize)]
+pub struct GlobOptions {
+    pub pattern: String,
+}
+
+#[derive(Deserialize)]
+pub struct TypeOptions {
+    #[serde(rename = "type")]
+    pub record_type: RecordFilter,
+}
+
+impl<'a> TryFrom<CompassArgs<'a>> for ClearOptions {
+    type Error = Error;
+
+    fn try_from(value: CompassArgs<'a>) -> Result<Self> {
+        let Some(&sub) = value.sub_cmds.first() else {
+            Err(InputError::FunctionArguments(
+                "no `clear` subcommand provided".to_owned(),
+            ))?
+        };
+
+        let get_arg = |name: &str| -> Result<&str> {
+            Ok(value.map_args.get(name).copied().ok_or_else(|| {
+                InputError::FunctionArguments(format!(
+                    "have chosen `{}` but not specified `{}`",
+                    sub, name
+                ))
+            })?)
+        };
+
+        match sub {
+            "buf" => Ok(Self::Buf(BufOptions {})),
+            "all" => Ok(Self::All(AllOptions {})),
+            "older" => Ok(Self::Older(OlderOptions {
+                than: get_arg("than")?.to_owned(),
+            })),
+            "frecency" => Ok(Self::Frecency(FrecencyOptions {
+                below: get_arg("below")?
+                    .parse::<i64>()
+                    .map_err(InputError::Int)?
+                    .into(),
+            })),
+            "glob" => Ok(Self::Glob(GlobOptions {
+                pattern: get_arg("pattern")?.to_owned(),
+            })),
+            "type" => Ok(Self::Type(TypeOptions {
+                record_type: get_arg("type")?.try_into()?,
+            })),
+
+            sub => Err(InputError::FunctionArguments(format!(
+                "unknown `clear` subcommand provided: {}",
+                sub
+            )))?,
+        }
+    }
+}
+
+mod tests {
+    use std::collections::HashMap;
+
+    use super::*;
+
+    #[test]
+    fn can_turn_compass_args_frecency() {
+        let mut map_args: HashMap<&str, &str> = HashMap::new();
+        map_args.insert("below", "10");
+
+        let args = CompassArgs {
+            main_cmd: "clear",
+            sub_cmds: vec!["frecency"],
+            map_args,
+        };
+
+        let got: ClearOptions = args.try_into().unwrap();
+
+        match got {
+            ClearOptions::Frecency(FrecencyOptions { below }) => assert_eq!(below, 10.into()),
+            _ => panic!("expected the frecency variant"),
+        }
+    }
+
+    #[test]
+    fn requires_subcommand_arguments() {
+        let args = CompassArgs {
+            main_cmd: "clear",
+            sub_cmds: vec!["glob"],
+            map_args: HashMap::new(),
+        };
+
+        assert!(TryInto::<ClearOptions>::try_into(args).is_err());
+    }
+}
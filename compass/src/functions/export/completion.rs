@@ -0,0 +1,9 @@
+use crate::viml::CompassArgs;
+
+pub fn get_export_completion(cargs: &CompassArgs) -> Vec<String> {
+    if cargs.sub_cmds.is_empty() {
+        return Vec::from(&["quickfix".to_owned(), "loclist".to_owned()]);
+    }
+
+    Vec::new()
+}
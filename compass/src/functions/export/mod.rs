@@ -0,0 +1,53 @@
+mod completion;
+use std::sync::Mutex;
+
+pub use completion::*;
+
+mod opts;
+use opts::*;
+
+use crate::{common_types::CursorPosition, state::Tracker, Result};
+
+use nvim_oxi::{api::call_function, Array, Dictionary, Object};
+
+pub fn get_export(
+    tracker: &'static Mutex<Tracker>,
+) -> impl Fn(Option<ExportOptions>) -> Result<()> {
+    move |opts: Option<ExportOptions>| {
+        let opts = opts.unwrap_or_default();
+
+        let items = {
+            let tracker = tracker.lock()?;
+            Array::from_iter(
+                tracker
+                    .list
+                    .iter_from_future()
+                    .filter(|r| r.buf.is_valid())
+                    .map(|r| {
+                        let CursorPosition { line, col } = r.lazy_extmark.pos(r.buf.clone());
+                        Dictionary::from_iter([
+                            ("bufnr", Object::from(r.buf.handle())),
+                            ("lnum", Object::from(line as i64)),
+                            ("col", Object::from(col as i64 + 1)),
+                            ("text", Object::from(r.note.clone().unwrap_or_default())),
+                        ])
+                    }),
+            )
+        };
+        let what = Dictionary::from_iter([
+            ("title", Object::from("Compass")),
+            ("items", Object::from(items)),
+        ]);
+
+        match opts {
+            ExportOptions::Quickfix => {
+                call_function::<_, i64>("setqflist", (Array::new(), " ", what))?
+            }
+            ExportOptions::Loclist => {
+                call_function::<_, i64>("setloclist", (0, Array::new(), " ", what))?
+            }
+        };
+
+        Ok(())
+    }
+}
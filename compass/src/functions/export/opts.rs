@@ -0,0 +1,57 @@
+use crate::{viml::CompassArgs, Error, InputError, Result};
+use macros::FromLua;
+
+use serde::Deserialize;
+
+/// Only the records of the current line of the list in use get exported,
+/// the ones stashed in the branches are left out on purpose
+#[derive(Debug, Default, Deserialize, FromLua)]
+#[serde(rename_all = "snake_case")]
+pub enum ExportOptions {
+    /// Pushes a new quickfix list on top of the stack, keeping the older ones
+    #[default]
+    Quickfix,
+    /// Pushes a new location list of the current window on top of its stack
+    Loclist,
+}
+
+impl TryFrom<CompassArgs<'_>> for ExportOptions {
+    type Error = Error;
+
+    fn try_from(value: CompassArgs) -> Result<Self> {
+        let Some(&sub) = value.sub_cmds.first() else {
+            Err(InputError::FunctionArguments(
+                "no `export` subcommand provided".to_owned(),
+            ))?
+        };
+
+        match sub {
+            "quickfix" => Ok(Self::Quickfix),
+            "loclist" => Ok(Self::Loclist),
+
+            sub => Err(InputError::FunctionArguments(format!(
+                "unknown `export` subcommand provided: {}",
+                sub
+            )))?,
+        }
+    }
+}
+
+mod tests {
+    use std::collections::HashMap;
+
+    use super::*;
+
+    #[test]
+    fn can_turn_compass_args_loclist() {
+        let args = CompassArgs {
+            main_cmd: "export",
+            sub_cmds: vec!["loclist"],
+            map_args: HashMap::new(),
+        };
+
+        let got: ExportOptions = args.try_into().unwrap();
+
+        assert!(matches!(got, ExportOptions::Loclist));
+    }
+}
@@ -1,14 +1,60 @@
-use crate::viml::CompassArgs;
+use crate::{state::Tracker, viml::CompassArgs};
+use std::sync::Mutex;
 
-pub fn get_goto_completion(cargs: &CompassArgs) -> Vec<String> {
+pub fn get_goto_completion(cargs: &CompassArgs, tracker: &'static Mutex<Tracker>) -> Vec<String> {
     let Some(first) = cargs.sub_cmds.first() else {
-        return Vec::from(&["relative".to_owned(), "absolute".to_owned()]);
+        return Vec::from(&[
+            "relative".to_owned(),
+            "absolute".to_owned(),
+            "nearest".to_owned(),
+            "slot".to_owned(),
+        ]);
     };
 
     match *first {
-        "relative" => Vec::from(&["direction=".to_owned()]),
-        "absolute" => Vec::from(&["target=".to_owned()]),
+        "relative" => Vec::from(&[
+            "direction=".to_owned(),
+            "count=".to_owned(),
+            "scope=".to_owned(),
+            "unit=".to_owned(),
+            "wrap=".to_owned(),
+        ]),
+        "absolute" => {
+            let mut completion = Vec::from(&[
+                "target=".to_owned(),
+                "id=".to_owned(),
+                "time=".to_owned(),
+                "scope=".to_owned(),
+            ]);
+            if let Ok(tracker) = tracker.lock() {
+                completion.extend(
+                    tracker
+                        .list
+                        .iter_from_future()
+                        .filter_map(|r| r.place_type.bookmark_name())
+                        .map(|name| format!("name={}", name)),
+                );
+            }
 
-        _ => Vec::from(&["relative".to_owned(), "absolute".to_owned()]),
+            completion
+        }
+
+        "nearest" => Vec::from(&["direction=".to_owned(), "wrap=".to_owned()]),
+
+        "slot" => {
+            let mut completion = Vec::from(&["n=".to_owned()]);
+            if let Ok(tracker) = tracker.lock() {
+                completion.extend(tracker.slot_completion());
+            }
+
+            completion
+        }
+
+        _ => Vec::from(&[
+            "relative".to_owned(),
+            "absolute".to_owned(),
+            "nearest".to_owned(),
+            "slot".to_owned(),
+        ]),
     }
 }
@@ -4,6 +4,7 @@ use std::sync::Mutex;
 pub use completion::*;
 
 mod opts;
+pub use opts::AbsoluteTarget;
 use opts::*;
 
 use crate::{common_types::Direction, state::Tracker, InputError, Result};
@@ -15,41 +16,71 @@ pub fn get_goto(tracker: &'static Mutex<Tracker>) -> impl Fn(Option<GotoOptions>
         let mut tracker = tracker.lock()?;
 
         match opts {
-            GotoOptions::Relative(RelativeOptions { direction }) => match direction {
-                Direction::Back => tracker.step_past(),
-                Direction::Forward => tracker.step_future(),
+            GotoOptions::Relative(RelativeOptions {
+                direction,
+                count,
+                scope,
+                unit,
+                wrap,
+            }) => match direction {
+                Direction::Back => tracker.step_past(count, scope, unit, wrap),
+                Direction::Forward => tracker.step_future(count, scope, unit, wrap),
             },
 
-            GotoOptions::Absolute(AbsoluteOptions {
-                target: AbsoluteTarget::Index(idx_record),
-            }) => tracker.goto_absolute(idx_record),
-
-            GotoOptions::Absolute(AbsoluteOptions {
-                target: AbsoluteTarget::Time(t),
-            }) => {
-                let idx_record = tracker
-                    .list
-                    .iter_from_future()
-                    .position(|r| r.buf == t.buf && r.frecency.latest_timestamp() == t.timestamp)
-                    .ok_or_else(|| InputError::NoRecords("no such record identified".to_owned()))?;
-                tracker.goto_absolute(idx_record)
+            GotoOptions::Nearest(NearestOptions { direction, wrap }) => {
+                tracker.goto_nearest(direction, wrap)
             }
 
-            GotoOptions::Absolute(AbsoluteOptions {
-                target: AbsoluteTarget::Tick(t),
-            }) => {
-                let idx_record = tracker
-                    .list
-                    .iter_from_future()
-                    .position(|r| {
-                        r.buf == t.buf
-                            && r.place_type
-                                .tick()
-                                .is_some_and(|rec_tick| rec_tick == t.tick)
-                    })
-                    .ok_or_else(|| InputError::NoRecords("no such record identified".to_owned()))?;
+            GotoOptions::Slot(SlotOptions { n }) => tracker.goto_slot(n),
+
+            GotoOptions::Absolute(AbsoluteOptions { target }) => {
+                let idx_record = find_absolute(&tracker, target)?;
                 tracker.goto_absolute(idx_record)
             }
         }
     }
 }
+
+/// Finds the index of the record the target points to
+pub fn find_absolute(tracker: &Tracker, target: AbsoluteTarget) -> Result<usize> {
+    let not_found = || InputError::NoRecords("no such record identified".to_owned());
+
+    Ok(match target {
+        AbsoluteTarget::Index(idx_record) => idx_record,
+
+        AbsoluteTarget::Id(id) => tracker
+            .list
+            .iter_from_future()
+            .position(|r| r.id == id)
+            .ok_or_else(|| InputError::NoRecords(format!("no record with id {}", id)))?,
+
+        AbsoluteTarget::Time(t) => tracker
+            .list
+            .iter_from_future()
+            .position(|r| r.buf == t.buf && r.frecency.latest_timestamp() == t.timestamp)
+            .ok_or_else(not_found)?,
+
+        AbsoluteTarget::Around(AroundTarget { timestamp, scope }) => tracker
+            .closest_in_time_idx(timestamp, scope)
+            .ok_or_else(|| {
+                InputError::NoRecords("no records to pick the closest in time from".to_owned())
+            })?,
+
+        AbsoluteTarget::Tick(t) => tracker
+            .list
+            .iter_from_future()
+            .position(|r| {
+                r.buf == t.buf
+                    && r.place_type
+                        .tick()
+                        .is_some_and(|rec_tick| rec_tick == t.tick)
+            })
+            .ok_or_else(not_found)?,
+
+        AbsoluteTarget::Name(name) => tracker
+            .list
+            .iter_from_future()
+            .position(|r| r.place_type.bookmark_name() == Some(&name))
+            .ok_or_else(|| InputError::NoRecords(format!("no bookmark named {}", name)))?,
+    })
+}
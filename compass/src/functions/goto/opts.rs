@@ -1,11 +1,13 @@
 use crate::{
-    common_types::{Direction, Timestamp},
-    state::{frecency::FrecencyType, Tick},
+    common_types::{Direction, Scope, Timestamp, Unit, VerticalDirection},
+    functions::slot::{deserialize_slot, parse_slot},
+    state::{frecency::FrecencyType, RecordId, Tick},
     viml::CompassArgs,
     Error, InputError, Result,
 };
 use macros::FromLua;
 
+use chrono::Local;
 use nvim_oxi::api::Buffer;
 use serde::Deserialize;
 
@@ -14,6 +16,8 @@ use serde::Deserialize;
 pub enum GotoOptions {
     Relative(RelativeOptions),
     Absolute(AbsoluteOptions),
+    Nearest(NearestOptions),
+    Slot(SlotOptions),
 }
 
 impl Default for GotoOptions {
@@ -25,21 +29,57 @@ impl Default for GotoOptions {
 #[derive(Debug, Deserialize)]
 pub struct RelativeOptions {
     pub direction: Direction,
+    /// How many records to move by at once
+    #[serde(default = "default_count")]
+    pub count: usize,
+    #[serde(default)]
+    pub scope: Scope,
+    #[serde(default)]
+    pub unit: Unit,
+    /// Continue from the other end of the list when there are no more records in the direction
+    #[serde(default)]
+    pub wrap: bool,
+}
+
+fn default_count() -> usize {
+    1
 }
 
 impl Default for RelativeOptions {
     fn default() -> Self {
         Self {
             direction: Direction::Back,
+            count: default_count(),
+            scope: Scope::default(),
+            unit: Unit::default(),
+            wrap: false,
         }
     }
 }
 
+#[derive(Debug, Default, Deserialize)]
+pub struct NearestOptions {
+    #[serde(default)]
+    pub direction: VerticalDirection,
+    /// Continue from the other end of the buffer when there are no more records in the direction
+    #[serde(default)]
+    pub wrap: bool,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct SlotOptions {
+    #[serde(deserialize_with = "deserialize_slot")]
+    pub n: u8,
+}
+
 #[derive(Debug, Deserialize)]
 pub enum AbsoluteTarget {
+    Id(RecordId),
     Time(TimeTarget),
+    Around(AroundTarget),
     Tick(TickTarget),
     Index(usize),
+    Name(String),
 }
 
 #[derive(Debug, Deserialize)]
@@ -54,11 +94,77 @@ pub struct TimeTarget {
     pub timestamp: Timestamp,
 }
 
+/// Picks the record visited closest to the moment
+#[derive(Debug, Deserialize)]
+pub struct AroundTarget {
+    pub timestamp: Timestamp,
+    #[serde(default = "default_around_scope")]
+    pub scope: Scope,
+}
+
+fn default_around_scope() -> Scope {
+    Scope::Buf
+}
+
 #[derive(Debug, Deserialize)]
 pub struct AbsoluteOptions {
     pub target: AbsoluteTarget,
 }
 
+impl TryFrom<&CompassArgs<'_>> for AbsoluteTarget {
+    type Error = Error;
+
+    fn try_from(value: &CompassArgs) -> Result<Self> {
+        if let Some(id_str) = value.map_args.get("id").copied() {
+            let id: u64 = id_str.parse().map_err(InputError::Int)?;
+            return Ok(Self::Id(id.into()));
+        };
+
+        if let Some(str_tick) = value.map_args.get("tick").copied() {
+            let target_tick: TickTarget =
+                serde_json::from_str(str_tick).map_err(InputError::Json)?;
+            return Ok(Self::Tick(target_tick));
+        }
+
+        if let Some(str_time) = value.map_args.get("time").copied() {
+            if str_time.trim_start().starts_with('{') {
+                let target_time: TimeTarget =
+                    serde_json::from_str(str_time).map_err(InputError::Json)?;
+                return Ok(Self::Time(target_time));
+            }
+
+            let timestamp = Timestamp::parse_human(str_time, Local::now()).ok_or_else(|| {
+                InputError::FunctionArguments(format!(
+                    "time has to look like `10m ago` or `14:30`, got: {}",
+                    str_time
+                ))
+            })?;
+            let scope: Scope = value
+                .map_args
+                .get("scope")
+                .copied()
+                .map(Scope::try_from)
+                .transpose()
+                .map_err(InputError::EnumParse)?
+                .unwrap_or_else(default_around_scope);
+            return Ok(Self::Around(AroundTarget { timestamp, scope }));
+        }
+
+        if let Some(name) = value.map_args.get("name").copied() {
+            return Ok(Self::Name(name.to_owned()));
+        };
+
+        if let Some(index_str) = value.map_args.get("index").copied() {
+            let index: usize = index_str.parse().map_err(InputError::Int)?;
+            return Ok(Self::Index(index));
+        };
+
+        Err(InputError::FunctionArguments(
+            "have chosen `absolute` but did not provide coordinates in a valid format".to_owned(),
+        ))?
+    }
+}
+
 impl TryFrom<CompassArgs<'_>> for GotoOptions {
     type Error = Error;
 
@@ -82,37 +188,77 @@ impl TryFrom<CompassArgs<'_>> for GotoOptions {
                     })?
                     .try_into()
                     .map_err(InputError::EnumParse)?;
+                let count = value
+                    .map_args
+                    .get("count")
+                    .map(|s| s.parse::<usize>())
+                    .transpose()
+                    .map_err(InputError::Int)?
+                    .unwrap_or_else(default_count);
+                let scope: Scope = value
+                    .map_args
+                    .get("scope")
+                    .copied()
+                    .map(Scope::try_from)
+                    .transpose()
+                    .map_err(InputError::EnumParse)?
+                    .unwrap_or_default();
+                let unit: Unit = value
+                    .map_args
+                    .get("unit")
+                    .copied()
+                    .map(Unit::try_from)
+                    .transpose()
+                    .map_err(InputError::EnumParse)?
+                    .unwrap_or_default();
+                let wrap = value
+                    .map_args
+                    .get("wrap")
+                    .map(|s| s.parse::<bool>())
+                    .transpose()
+                    .map_err(InputError::Bool)?
+                    .unwrap_or_default();
 
-                Ok(Self::Relative(RelativeOptions { direction }))
+                Ok(Self::Relative(RelativeOptions {
+                    direction,
+                    count,
+                    scope,
+                    unit,
+                    wrap,
+                }))
             }
-            "absolute" => {
-                if let Some(str_tick) = value.map_args.get("tick").copied() {
-                    let target_tick: TickTarget =
-                        serde_json::from_str(str_tick).map_err(InputError::Json)?;
-                    return Ok(Self::Absolute(AbsoluteOptions {
-                        target: AbsoluteTarget::Tick(target_tick),
-                    }));
-                }
+            "absolute" => Ok(Self::Absolute(AbsoluteOptions {
+                target: (&value).try_into()?,
+            })),
 
-                if let Some(str_time) = value.map_args.get("time").copied() {
-                    let target_time: TimeTarget =
-                        serde_json::from_str(str_time).map_err(InputError::Json)?;
-                    return Ok(Self::Absolute(AbsoluteOptions {
-                        target: AbsoluteTarget::Time(target_time),
-                    }));
-                }
+            "nearest" => {
+                let direction: VerticalDirection = value
+                    .map_args
+                    .get("direction")
+                    .copied()
+                    .map(VerticalDirection::try_from)
+                    .transpose()
+                    .map_err(InputError::EnumParse)?
+                    .unwrap_or_default();
+                let wrap = value
+                    .map_args
+                    .get("wrap")
+                    .map(|s| s.parse::<bool>())
+                    .transpose()
+                    .map_err(InputError::Bool)?
+                    .unwrap_or_default();
+
+                Ok(Self::Nearest(NearestOptions { direction, wrap }))
+            }
 
-                if let Some(index_str) = value.map_args.get("index").copied() {
-                    let index: usize = index_str.parse().map_err(InputError::Int)?;
-                    return Ok(Self::Absolute(AbsoluteOptions {
-                        target: AbsoluteTarget::Index(index),
-                    }));
-                };
-
-                Err(InputError::FunctionArguments(
-                    "have chosen `absolute` but did not provide coordinates in a valid format"
-                        .to_owned(),
-                ))?
+            "slot" => {
+                let n = parse_slot(value.map_args.get("n").ok_or_else(|| {
+                    InputError::FunctionArguments(
+                        "have chosen `slot` but not specified the slot number".to_owned(),
+                    )
+                })?)?;
+
+                Ok(Self::Slot(SlotOptions { n }))
             }
 
             sub => Err(InputError::FunctionArguments(format!(
@@ -128,6 +274,8 @@ impl From<GotoOptions> for FrecencyType {
         match value {
             GotoOptions::Relative(_) => Self::RelativeGoto,
             GotoOptions::Absolute(_) => Self::AbsoluteGoto,
+            GotoOptions::Nearest(_) => Self::AbsoluteGoto,
+            GotoOptions::Slot(_) => Self::AbsoluteGoto,
         }
     }
 }
@@ -164,4 +312,150 @@ mod tests {
             _ => panic!("got: {:?}", got),
         }
     }
+
+    #[test]
+    fn can_turn_compass_args_relative_count() {
+        let mut map_args: HashMap<&str, &str> = HashMap::new();
+        map_args.insert("direction", "back");
+        map_args.insert("count", "3");
+
+        let args = CompassArgs {
+            main_cmd: "goto",
+            sub_cmds: vec!["relative"],
+            map_args,
+        };
+
+        let got: GotoOptions = args.try_into().unwrap();
+
+        match got {
+            GotoOptions::Relative(RelativeOptions { count, .. }) => assert_eq!(count, 3),
+
+            _ => panic!("got: {:?}", got),
+        }
+    }
+
+    #[test]
+    fn can_turn_compass_args_nearest() {
+        let mut map_args: HashMap<&str, &str> = HashMap::new();
+        map_args.insert("direction", "up");
+        map_args.insert("wrap", "true");
+
+        let args = CompassArgs {
+            main_cmd: "goto",
+            sub_cmds: vec!["nearest"],
+            map_args,
+        };
+
+        let got: GotoOptions = args.try_into().unwrap();
+
+        match got {
+            GotoOptions::Nearest(NearestOptions { direction, wrap }) => {
+                assert_eq!(direction, VerticalDirection::Up);
+                assert!(wrap);
+            }
+
+            _ => panic!("got: {:?}", got),
+        }
+    }
+
+    #[test]
+    fn can_turn_compass_args_slot() {
+        let mut map_args: HashMap<&str, &str> = HashMap::new();
+        map_args.insert("n", "2");
+
+        let args = CompassArgs {
+            main_cmd: "goto",
+            sub_cmds: vec!["slot"],
+            map_args,
+        };
+
+        let got: GotoOptions = args.try_into().unwrap();
+
+        match got {
+            GotoOptions::Slot(SlotOptions { n }) => assert_eq!(n, 2),
+
+            _ => panic!("got: {:?}", got),
+        }
+    }
+
+    #[test]
+    fn rejects_slot_out_of_range() {
+        let mut map_args: HashMap<&str, &str> = HashMap::new();
+        map_args.insert("n", "100");
+
+        let args = CompassArgs {
+            main_cmd: "goto",
+            sub_cmds: vec!["slot"],
+            map_args,
+        };
+
+        assert!(TryInto::<GotoOptions>::try_into(args).is_err());
+    }
+
+    #[test]
+    fn can_turn_compass_args_id() {
+        let mut map_args: HashMap<&str, &str> = HashMap::new();
+        map_args.insert("id", "17");
+
+        let args = CompassArgs {
+            main_cmd: "goto",
+            sub_cmds: vec!["absolute"],
+            map_args,
+        };
+
+        let got: GotoOptions = args.try_into().unwrap();
+
+        match got {
+            GotoOptions::Absolute(AbsoluteOptions {
+                target: AbsoluteTarget::Id(id),
+            }) => assert_eq!(id, 17.into()),
+
+            _ => panic!("got: {:?}", got),
+        }
+    }
+
+    #[test]
+    fn can_turn_compass_args_human_time() {
+        let mut map_args: HashMap<&str, &str> = HashMap::new();
+        map_args.insert("time", "5m ago");
+        map_args.insert("scope", "global");
+
+        let args = CompassArgs {
+            main_cmd: "goto",
+            sub_cmds: vec!["absolute"],
+            map_args,
+        };
+
+        let got: GotoOptions = args.try_into().unwrap();
+
+        match got {
+            GotoOptions::Absolute(AbsoluteOptions {
+                target: AbsoluteTarget::Around(AroundTarget { scope, .. }),
+            }) => assert_eq!(scope, Scope::Global),
+
+            _ => panic!("got: {:?}", got),
+        }
+    }
+
+    #[test]
+    fn can_turn_compass_args_bookmark_name() {
+        let mut map_args: HashMap<&str, &str> = HashMap::new();
+        map_args.insert("name", "foo");
+
+        let args = CompassArgs {
+            main_cmd: "goto",
+            sub_cmds: vec!["absolute"],
+            map_args,
+        };
+
+        let got: GotoOptions = args.try_into().unwrap();
+
+        match got {
+            GotoOptions::Absolute(AbsoluteOptions {
+                target: AbsoluteTarget::Name(name),
+            }) => assert_eq!(name, "foo"),
+
+            _ => panic!("got: {:?}", got),
+        }
+    }
 }
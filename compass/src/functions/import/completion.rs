@@ -0,0 +1,25 @@
+use crate::viml::CompassArgs;
+
+pub fn get_import_completion(cargs: &CompassArgs) -> Vec<String> {
+    let subcommands = || {
+        Vec::from(&[
+            "jumplist".to_owned(),
+            "changelist".to_owned(),
+            "marks".to_owned(),
+            "quickfix".to_owned(),
+            "loclist".to_owned(),
+        ])
+    };
+
+    let Some(first) = cargs.sub_cmds.first() else {
+        return subcommands();
+    };
+
+    match *first {
+        "jumplist" | "changelist" => Vec::from(&["max=".to_owned()]),
+        "marks" => Vec::from(&["global=".to_owned()]),
+        "quickfix" | "loclist" => Vec::new(),
+
+        _ => subcommands(),
+    }
+}
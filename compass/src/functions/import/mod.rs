@@ -0,0 +1,36 @@
+mod completion;
+use std::sync::Mutex;
+
+pub use completion::*;
+
+mod opts;
+use opts::*;
+
+use crate::{
+    state::{changelist_records, jumplist_records, mark_records, quickfix_records, Tracker},
+    Result,
+};
+
+use nvim_oxi::api::get_current_buf;
+
+pub fn get_import(
+    tracker: &'static Mutex<Tracker>,
+) -> impl Fn(Option<ImportOptions>) -> Result<()> {
+    move |opts: Option<ImportOptions>| {
+        let opts = opts.unwrap_or_default();
+
+        let records = match opts {
+            ImportOptions::Jumplist(ListOptions { max }) => jumplist_records(max)?,
+            ImportOptions::Changelist(ListOptions { max }) => {
+                changelist_records(&get_current_buf(), max)?
+            }
+            ImportOptions::Marks(MarksOptions { global }) => {
+                mark_records(&get_current_buf(), global)?
+            }
+            ImportOptions::Quickfix => quickfix_records(false)?,
+            ImportOptions::Loclist => quickfix_records(true)?,
+        };
+
+        tracker.lock()?.import(records)
+    }
+}
@@ -0,0 +1,139 @@
+use crate::{viml::CompassArgs, Error, InputError, Result};
+use macros::FromLua;
+
+use serde::Deserialize;
+
+#[derive(Debug, Deserialize, FromLua)]
+#[serde(rename_all = "snake_case")]
+pub enum ImportOptions {
+    /// Entries of the current window's jumplist
+    Jumplist(ListOptions),
+    /// Changes made in the current buffer
+    Changelist(ListOptions),
+    /// Lettered marks, the local ones of the current buffer and the global ones
+    Marks(MarksOptions),
+    /// Valid entries of the quickfix list
+    Quickfix,
+    /// Valid entries of the current window's location list
+    Loclist,
+}
+
+impl Default for ImportOptions {
+    fn default() -> Self {
+        Self::Jumplist(ListOptions::default())
+    }
+}
+
+#[derive(Debug, Deserialize)]
+pub struct ListOptions {
+    /// How many of the most recent entries to import
+    #[serde(default = "default_max")]
+    pub max: usize,
+}
+
+fn default_max() -> usize {
+    20
+}
+
+impl Default for ListOptions {
+    fn default() -> Self {
+        Self { max: default_max() }
+    }
+}
+
+#[derive(Debug, Deserialize)]
+pub struct MarksOptions {
+    /// Whether to include the global marks placed in the other files
+    #[serde(default = "default_global")]
+    pub global: bool,
+}
+
+fn default_global() -> bool {
+    true
+}
+
+impl TryFrom<CompassArgs<'_>> for ImportOptions {
+    type Error = Error;
+
+    fn try_from(value: CompassArgs) -> Result<Self> {
+        let Some(&sub) = value.sub_cmds.first() else {
+            Err(InputError::FunctionArguments(
+                "no `import` subcommand provided".to_owned(),
+            ))?
+        };
+
+        let max = value
+            .map_args
+            .get("max")
+            .map(|s| s.parse::<usize>())
+            .transpose()
+            .map_err(InputError::Int)?
+            .unwrap_or_else(default_max);
+
+        match sub {
+            "jumplist" => Ok(Self::Jumplist(ListOptions { max })),
+            "changelist" => Ok(Self::Changelist(ListOptions { max })),
+            "marks" => {
+                let global = value
+                    .map_args
+                    .get("global")
+                    .map(|s| s.parse::<bool>())
+                    .transpose()
+                    .map_err(InputError::Bool)?
+                    .unwrap_or_else(default_global);
+
+                Ok(Self::Marks(MarksOptions { global }))
+            }
+            "quickfix" => Ok(Self::Quickfix),
+            "loclist" => Ok(Self::Loclist),
+
+            sub => Err(InputError::FunctionArguments(format!(
+                "unknown `import` subcommand provided: {}",
+                sub
+            )))?,
+        }
+    }
+}
+
+mod tests {
+    use std::collections::HashMap;
+
+    use super::*;
+
+    #[test]
+    fn can_turn_compass_args_jumplist() {
+        let mut map_args: HashMap<&str, &str> = HashMap::new();
+        map_args.insert("max", "5");
+
+        let args = CompassArgs {
+            main_cmd: "import",
+            sub_cmds: vec!["jumplist"],
+            map_args,
+        };
+
+        let got: ImportOptions = args.try_into().unwrap();
+
+        match got {
+            ImportOptions::Jumplist(ListOptions { max }) => assert_eq!(max, 5),
+            _ => panic!("expected the jumplist variant"),
+        }
+    }
+    #[test]
+    fn can_turn_compass_args_marks() {
+        let mut map_args: HashMap<&str, &str> = HashMap::new();
+        map_args.insert("global", "false");
+
+        let args = CompassArgs {
+            main_cmd: "import",
+            sub_cmds: vec!["marks"],
+            map_args,
+        };
+
+        let got: ImportOptions = args.try_into().unwrap();
+
+        match got {
+            ImportOptions::Marks(MarksOptions { global }) => assert!(!global),
+            _ => panic!("expected the marks variant"),
+        }
+    }
+}
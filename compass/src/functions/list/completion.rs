@@ -0,0 +1,49 @@
+use crate::{state::Tracker, viml::CompassArgs};
+use std::sync::Mutex;
+
+pub fn get_list_completion(cargs: &CompassArgs, tracker: &'static Mutex<Tracker>) -> Vec<String> {
+    let Some(first) = cargs.sub_cmds.first() else {
+        return Vec::from(&[
+            "new".to_owned(),
+            "switch".to_owned(),
+            "delete".to_owned(),
+            "rename".to_owned(),
+            "move".to_owned(),
+            "up".to_owned(),
+            "down".to_owned(),
+        ]);
+    };
+
+    match *first {
+        "switch" | "delete" => {
+            let Ok(tracker) = tracker.lock() else {
+                return Vec::from(&["name=".to_owned()]);
+            };
+
+            let mut completion: Vec<String> = tracker
+                .workspaces
+                .iter()
+                .rev()
+                .map(|w| format!("name={}", w.name))
+                .collect();
+            if *first == "delete" {
+                completion.push(format!("name={}", tracker.list_name));
+            }
+
+            completion
+        }
+        "new" | "rename" => Vec::from(&["name=".to_owned()]),
+        "move" => Vec::from(&["id=".to_owned(), "to=".to_owned()]),
+        "up" | "down" => Vec::from(&["id=".to_owned()]),
+
+        _ => Vec::from(&[
+            "new".to_owned(),
+            "switch".to_owned(),
+            "delete".to_owned(),
+            "rename".to_owned(),
+            "move".to_owned(),
+            "up".to_owned(),
+            "down".to_owned(),
+        ]),
+    }
+}
@@ -0,0 +1,77 @@
+mod completion;
+use std::sync::Mutex;
+
+pub use completion::*;
+
+mod opts;
+use opts::*;
+
+use crate::{
+    common_types::CursorPosition,
+    state::{RecordId, Tracker},
+    InputError, Result,
+};
+
+use nvim_oxi::api::{get_current_buf, get_current_win};
+
+pub fn get_list(tracker: &'static Mutex<Tracker>) -> impl Fn(Option<ListOptions>) -> Result<()> {
+    move |opts: Option<ListOptions>| {
+        let opts = opts.unwrap_or_default();
+
+        let mut tracker = tracker.lock()?;
+
+        match opts {
+            ListOptions::New(NameOptions { name }) => tracker.new_list(name),
+            ListOptions::Switch(SwitchOptions { name: Some(name) }) => tracker.switch_list(&name),
+            ListOptions::Switch(SwitchOptions { name: None }) => {
+                let name = tracker
+                    .workspaces
+                    .last()
+                    .map(|w| w.name.clone())
+                    .ok_or_else(|| {
+                        InputError::NoRecords("there are no other lists to switch to".to_owned())
+                    })?;
+                tracker.switch_list(&name)
+            }
+            ListOptions::Delete(NameOptions { name }) => tracker.delete_list(&name),
+            ListOptions::Rename(NameOptions { name }) => tracker.rename_list(name),
+            ListOptions::Move(MoveOptions { id, to }) => {
+                let from = record_idx(&mut tracker, id)?;
+                tracker.reorder(from, to)
+            }
+            ListOptions::Up(ShiftOptions { id }) => {
+                let from = record_idx(&mut tracker, id)?;
+                let to = from.checked_sub(1).ok_or_else(|| {
+                    InputError::NoRecords("the record is already the newest".to_owned())
+                })?;
+                tracker.reorder(from, to)
+            }
+            ListOptions::Down(ShiftOptions { id }) => {
+                let from = record_idx(&mut tracker, id)?;
+                tracker.reorder(from, from + 1)
+            }
+        }
+    }
+}
+
+/// Finds the record by its id or the one nearby the cursor
+fn record_idx(tracker: &mut Tracker, id: Option<RecordId>) -> Result<usize> {
+    match id {
+        Some(id) => Ok(tracker
+            .list
+            .iter_from_future()
+            .position(|r| r.id == id)
+            .ok_or_else(|| InputError::NoRecords(format!("no record with id {}", id)))?),
+        None => {
+            let buf_curr = get_current_buf();
+            let pos_curr: CursorPosition = get_current_win().get_cursor()?.into();
+
+            tracker.activate_first()?;
+            Ok(tracker
+                .closest_record_idx(&buf_curr, &pos_curr)
+                .ok_or_else(|| {
+                    InputError::NoRecords("no record nearby the cursor to reorder".to_owned())
+                })?)
+        }
+    }
+}
@@ -0,0 +1,180 @@
+use crate::{state::RecordId, viml::CompassArgs, Error, InputError, Result};
+use macros::FromLua;
+
+use serde::Deserialize;
+
+#[derive(Debug, Deserialize, FromLua)]
+#[serde(rename_all = "snake_case")]
+pub enum ListOptions {
+    New(NameOptions),
+    Switch(SwitchOptions),
+    Delete(NameOptions),
+    /// Renames the list in use
+    Rename(NameOptions),
+    /// Puts the record under another index of the list in use
+    Move(MoveOptions),
+    /// Swaps the record with the newer neighbour
+    Up(ShiftOptions),
+    /// Swaps the record with the older neighbour
+    Down(ShiftOptions),
+}
+
+impl Default for ListOptions {
+    fn default() -> Self {
+        Self::Switch(SwitchOptions::default())
+    }
+}
+
+#[derive(Debug, Deserialize)]
+pub struct NameOptions {
+    pub name: String,
+}
+
+#[derive(Debug, Default, Deserialize)]
+pub struct SwitchOptions {
+    /// Goes back to the previously used list when not provided
+    #[serde(default)]
+    pub name: Option<String>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct MoveOptions {
+    /// Record nearby the cursor when not provided
+    #[serde(default)]
+    pub id: Option<RecordId>,
+    /// Index counting from the newest record
+    pub to: usize,
+}
+
+#[derive(Debug, Default, Deserialize)]
+pub struct ShiftOptions {
+    /// Record nearby the cursor when not provided
+    #[serde(default)]
+    pub id: Option<RecordId>,
+}
+
+impl<'a> TryFrom<CompassArgs<'a>> for ListOptions {
+    type Error = Error;
+
+    fn try_from(value: CompassArgs<'a>) -> Result<Self> {
+        let Some(&sub) = value.sub_cmds.first() else {
+            Err(InputError::FunctionArguments(
+                "no `list` subcommand provided".to_owned(),
+            ))?
+        };
+
+        let name = value.map_args.get("name").map(|&s| s.to_owned());
+        let required_name = name.clone().ok_or_else(|| {
+            InputError::FunctionArguments(format!(
+                "have chosen `{}` but did not provide the list name",
+                sub
+            ))
+        });
+
+        let id: Option<RecordId> = value
+            .map_args
+            .get("id")
+            .map(|s| s.parse::<u64>())
+            .transpose()
+            .map_err(InputError::Int)?
+            .map(Into::into);
+
+        match sub {
+            "new" => Ok(Self::New(NameOptions {
+                name: required_name?,
+            })),
+            "switch" => Ok(Self::Switch(SwitchOptions { name })),
+            "delete" => Ok(Self::Delete(NameOptions {
+                name: required_name?,
+            })),
+            "rename" => Ok(Self::Rename(NameOptions {
+                name: required_name?,
+            })),
+            "move" => {
+                let to = value
+                    .map_args
+                    .get("to")
+                    .ok_or_else(|| {
+                        InputError::FunctionArguments(
+                            "have chosen `move` but did not provide the index to move to"
+                                .to_owned(),
+                        )
+                    })?
+                    .parse::<usize>()
+                    .map_err(InputError::Int)?;
+
+                Ok(Self::Move(MoveOptions { id, to }))
+            }
+            "up" => Ok(Self::Up(ShiftOptions { id })),
+            "down" => Ok(Self::Down(ShiftOptions { id })),
+
+            sub => Err(InputError::FunctionArguments(format!(
+                "unknown `list` subcommand provided: {}",
+                sub
+            )))?,
+        }
+    }
+}
+
+mod tests {
+    use std::collections::HashMap;
+
+    use super::*;
+
+    #[test]
+    fn can_turn_compass_args_switch() {
+        let mut map_args: HashMap<&str, &str> = HashMap::new();
+        map_args.insert("name", "refactor");
+
+        let args = CompassArgs {
+            main_cmd: "list",
+            sub_cmds: vec!["switch"],
+            map_args,
+        };
+
+        let got: ListOptions = args.try_into().unwrap();
+
+        match got {
+            ListOptions::Switch(SwitchOptions { name }) => {
+                assert_eq!(name.as_deref(), Some("refactor"))
+            }
+
+            _ => panic!("got: {:?}", got),
+        }
+    }
+
+    #[test]
+    fn can_turn_compass_args_move() {
+        let mut map_args: HashMap<&str, &str> = HashMap::new();
+        map_args.insert("id", "12");
+        map_args.insert("to", "0");
+
+        let args = CompassArgs {
+            main_cmd: "list",
+            sub_cmds: vec!["move"],
+            map_args,
+        };
+
+        let got: ListOptions = args.try_into().unwrap();
+
+        match got {
+            ListOptions::Move(MoveOptions { id, to }) => {
+                assert_eq!(id, Some(12.into()));
+                assert_eq!(to, 0);
+            }
+
+            _ => panic!("got: {:?}", got),
+        }
+    }
+
+    #[test]
+    fn requires_list_name() {
+        let args = CompassArgs {
+            main_cmd: "list",
+            sub_cmds: vec!["new"],
+            map_args: HashMap::new(),
+        };
+
+        assert!(ListOptions::try_from(args).is_err());
+    }
+}
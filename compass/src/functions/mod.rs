@@ -14,4 +14,24 @@ pub mod follow;
 
 pub mod pop;
 
+pub mod pin;
+
+pub mod note;
+
+pub mod branch;
+
+pub mod list;
+
+pub mod slot;
+
+pub mod restore;
+
+pub mod clear;
+
+pub mod r#move;
+
+pub mod import;
+
+pub mod export;
+
 macros::functions_and_commands!("./src/functions");
@@ -0,0 +1,19 @@
+use crate::viml::CompassArgs;
+
+pub fn get_move_completion(cargs: &CompassArgs) -> Vec<String> {
+    let Some(first) = cargs.sub_cmds.first() else {
+        return Vec::from(&["pick".to_owned(), "absolute".to_owned()]);
+    };
+
+    match *first {
+        "pick" => Vec::from(&["max_windows=".to_owned()]),
+        "absolute" => Vec::from(&[
+            "index=".to_owned(),
+            "id=".to_owned(),
+            "time=".to_owned(),
+            "name=".to_owned(),
+        ]),
+
+        _ => Vec::from(&["pick".to_owned(), "absolute".to_owned()]),
+    }
+}
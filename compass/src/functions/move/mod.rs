@@ -0,0 +1,120 @@
+mod completion;
+use std::sync::Mutex;
+
+pub use completion::*;
+
+mod opts;
+use opts::*;
+
+use crate::{
+    common_types::CursorPosition,
+    config::get_config,
+    functions::{goto::find_absolute, open::get_unique_bufs_priority},
+    state::Tracker,
+    ui::{
+        grid::{open_grid, GridLayout},
+        record_mark::recreate_mark_time,
+    },
+    InputError, Result,
+};
+
+use nvim_oxi::api::{get_current_buf, get_current_win};
+
+pub fn get_move(tracker: &'static Mutex<Tracker>) -> impl Fn(Option<MoveOptions>) -> Result<()> {
+    move |opts: Option<MoveOptions>| {
+        let opts = opts.unwrap_or_default();
+
+        let mut tracker = tracker.lock()?;
+        match opts {
+            MoveOptions::Pick(PickOptions { max_windows }) => {
+                if tracker.list.is_empty() {
+                    Err(InputError::NoRecords("record list is empty".to_owned()))?
+                }
+
+                tracker.activate_first()?;
+
+                // The chosen hint runs `move absolute` back at the cursor the grid was opened from
+                let record_list = get_unique_bufs_priority(max_windows, &mut tracker.list)?;
+                open_grid(
+                    &record_list,
+                    max_windows,
+                    GridLayout::Move,
+                    get_config().picker.jump_keys.iter(),
+                )
+            }
+
+            MoveOptions::Absolute(AbsoluteOptions { target }) => {
+                let idx_record = find_absolute(&tracker, target)?;
+                move_record(&mut tracker, idx_record)
+            }
+        }
+    }
+}
+
+/// Relocates the record to the cursor keeping its place in the list and its frecency,
+/// refusing to when another record is already nearby the cursor
+fn move_record(tracker: &mut Tracker, idx_record: usize) -> Result<()> {
+    let buf_curr = get_current_buf();
+    let pos_curr: CursorPosition = get_current_win().get_cursor()?.into();
+
+    tracker.sync_cursor();
+
+    tracker.activate_first()?;
+    if tracker.list.get(idx_record).is_none() {
+        Err(InputError::NoRecords(format!(
+            "non-existent index for move provided: {}",
+            idx_record
+        )))?
+    }
+    if tracker.list.iter_from_future().enumerate().any(|(i, r)| {
+        i != idx_record
+            && r.buf == buf_curr
+            && r.lazy_extmark.pos(buf_curr.clone()).is_nearby(&pos_curr)
+    }) {
+        Err(InputError::Other(
+            "there is already a mark nearby the cursor".to_owned(),
+        ))?
+    }
+
+    let pos = tracker.list.pos;
+    let record = tracker.list.get_mut(idx_record).ok_or_else(|| {
+        InputError::NoRecords(format!(
+            "non-existent index for move provided: {}",
+            idx_record
+        ))
+    })?;
+    record.relocate(buf_curr, pos_curr, recreate_mark_time(idx_record, pos))
+}
+
+mod tests {
+    use crate::state::{ChangeTypeRecord, PlaceTypeRecord, Record};
+
+    use super::*;
+
+    use nvim_oxi::api::{create_buf, Buffer};
+
+    #[nvim_oxi::test]
+    fn refuses_to_move_onto_another_record() {
+        let mut tracker = Tracker::default();
+        tracker.list.push(
+            Record::try_new(
+                Buffer::current(),
+                PlaceTypeRecord::Change(ChangeTypeRecord::Tick(4.into())),
+                &CursorPosition::from((1, 2)),
+            )
+            .unwrap(),
+        );
+        let other_buf = create_buf(true, false).unwrap();
+        tracker.list.push(
+            Record::try_new(
+                other_buf.clone(),
+                PlaceTypeRecord::Change(ChangeTypeRecord::Tick(11.into())),
+                &CursorPosition::from((1, 0)),
+            )
+            .unwrap(),
+        );
+
+        assert!(move_record(&mut tracker, 0).is_err());
+        assert_eq!(tracker.list.get(0).unwrap().buf, other_buf);
+    }
+}
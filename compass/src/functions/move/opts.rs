@@ -0,0 +1,77 @@
+use crate::{
+    config::{get_config, WindowGridSize},
+    functions::goto::AbsoluteTarget,
+    viml::CompassArgs,
+    Error, InputError, Result,
+};
+use macros::FromLua;
+
+use serde::Deserialize;
+
+#[derive(Debug, Deserialize, FromLua)]
+#[serde(rename_all = "snake_case")]
+pub enum MoveOptions {
+    /// Choose the record to move in a grid of windows
+    Pick(PickOptions),
+    Absolute(AbsoluteOptions),
+}
+
+impl Default for MoveOptions {
+    fn default() -> Self {
+        Self::Pick(PickOptions::default())
+    }
+}
+
+#[derive(Debug, Deserialize)]
+pub struct PickOptions {
+    #[serde(default = "default_max_windows")]
+    pub max_windows: WindowGridSize,
+}
+
+fn default_max_windows() -> WindowGridSize {
+    get_config().picker.max_windows
+}
+
+impl Default for PickOptions {
+    fn default() -> Self {
+        Self {
+            max_windows: default_max_windows(),
+        }
+    }
+}
+
+#[derive(Debug, Deserialize)]
+pub struct AbsoluteOptions {
+    pub target: AbsoluteTarget,
+}
+
+impl TryFrom<CompassArgs<'_>> for MoveOptions {
+    type Error = Error;
+
+    fn try_from(value: CompassArgs) -> Result<Self> {
+        let Some(&sub) = value.sub_cmds.first() else {
+            Err(InputError::FunctionArguments(
+                "no `move` subcommand provided".to_owned(),
+            ))?
+        };
+
+        match sub {
+            "pick" => Ok(Self::Pick(PickOptions {
+                max_windows: value
+                    .map_args
+                    .get("max_windows")
+                    .map(|&s| s.try_into())
+                    .transpose()?
+                    .unwrap_or_else(default_max_windows),
+            })),
+            "absolute" => Ok(Self::Absolute(AbsoluteOptions {
+                target: (&value).try_into()?,
+            })),
+
+            sub => Err(InputError::FunctionArguments(format!(
+                "unknown `move` subcommand provided: {}",
+                sub
+            )))?,
+        }
+    }
+}
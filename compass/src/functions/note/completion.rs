@@ -0,0 +1,13 @@
+use crate::viml::CompassArgs;
+
+pub fn get_note_completion(cargs: &CompassArgs) -> Vec<String> {
+    let Some(first) = cargs.sub_cmds.first() else {
+        return Vec::from(&["edit".to_owned(), "clear".to_owned()]);
+    };
+
+    match *first {
+        "edit" => Vec::from(&["note=".to_owned()]),
+
+        _ => Vec::from(&["edit".to_owned(), "clear".to_owned()]),
+    }
+}
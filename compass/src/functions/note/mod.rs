@@ -0,0 +1,70 @@
+mod completion;
+use std::sync::Mutex;
+
+pub use completion::*;
+
+mod opts;
+use opts::*;
+
+use crate::{
+    common_types::CursorPosition, state::Tracker, ui::record_mark::recreate_mark_time, InputError,
+    Result,
+};
+
+use nvim_oxi::api::{call_function, get_current_buf, get_current_win};
+
+pub fn get_note(tracker: &'static Mutex<Tracker>) -> impl Fn(Option<NoteOptions>) -> Result<()> {
+    move |opts: Option<NoteOptions>| {
+        let opts = opts.unwrap_or_default();
+
+        match opts {
+            NoteOptions::Edit(EditOptions { note }) => {
+                let note = match note {
+                    Some(n) => n,
+                    None => {
+                        // Prompt without holding the lock to not stall the tracker
+                        let old_note = {
+                            let mut tracker = tracker.lock()?;
+                            let i = closest_record_idx(&mut tracker)?;
+                            tracker.list.get(i).and_then(|r| r.note.clone())
+                        };
+                        call_function("input", ("Note: ", old_note.unwrap_or_default()))?
+                    }
+                };
+
+                let mut tracker = tracker.lock()?;
+                set_note(&mut tracker, Some(note).filter(|n| !n.is_empty()))
+            }
+
+            NoteOptions::Clear(ClearOptions {}) => {
+                let mut tracker = tracker.lock()?;
+                set_note(&mut tracker, None)
+            }
+        }
+    }
+}
+
+fn closest_record_idx(tracker: &mut Tracker) -> Result<usize> {
+    let buf_curr = get_current_buf();
+    let pos_curr: CursorPosition = get_current_win().get_cursor()?.into();
+
+    tracker.sync_cursor();
+
+    tracker.activate_first()?;
+    Ok(tracker
+        .closest_record_idx(&buf_curr, &pos_curr)
+        .ok_or_else(|| {
+            InputError::NoRecords("no record nearby the cursor to set the note of".to_owned())
+        })?)
+}
+
+fn set_note(tracker: &mut Tracker, note: Option<String>) -> Result<()> {
+    let i = closest_record_idx(tracker)?;
+
+    let pos = tracker.list.pos;
+    if let Some(r) = tracker.list.get_mut(i) {
+        r.set_note(note, recreate_mark_time(i, pos))?;
+    }
+
+    Ok(())
+}
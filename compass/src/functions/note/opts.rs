@@ -0,0 +1,51 @@
+use crate::{viml::CompassArgs, Error, InputError, Result};
+use macros::FromLua;
+
+use serde::Deserialize;
+
+#[derive(Deserialize, FromLua)]
+#[serde(rename_all = "snake_case")]
+pub enum NoteOptions {
+    Edit(EditOptions),
+    Clear(ClearOptions),
+}
+
+impl Default for NoteOptions {
+    fn default() -> Self {
+        NoteOptions::Edit(EditOptions::default())
+    }
+}
+
+#[derive(Default, Deserialize)]
+pub struct EditOptions {
+    /// Prompt for the note when not provided
+    #[serde(default)]
+    pub note: Option<String>,
+}
+
+#[derive(Default, Deserialize)]
+pub struct ClearOptions {}
+
+impl<'a> TryFrom<CompassArgs<'a>> for NoteOptions {
+    type Error = Error;
+
+    fn try_from(value: CompassArgs<'a>) -> Result<Self> {
+        let Some(&sub) = value.sub_cmds.first() else {
+            Err(InputError::FunctionArguments(
+                "no `note` subcommand provided".to_owned(),
+            ))?
+        };
+
+        match sub {
+            "edit" => Ok(Self::Edit(EditOptions {
+                note: value.map_args.get("note").map(|&n| n.to_owned()),
+            })),
+            "clear" => Ok(Self::Clear(ClearOptions {})),
+
+            sub => Err(InputError::FunctionArguments(format!(
+                "unknown `note` subcommand provided: {}",
+                sub
+            )))?,
+        }
+    }
+}
@@ -1,3 +1,12 @@
-pub fn get_open_completion() -> Vec<String> {
-    Vec::from(&["record_types=".to_owned(), "max_windows=".to_owned()])
+use crate::viml::CompassArgs;
+
+pub fn get_open_completion(cargs: &CompassArgs) -> Vec<String> {
+    match cargs.sub_cmds.first() {
+        Some(&"sessions") => Vec::new(),
+        _ => Vec::from(&[
+            "sessions".to_owned(),
+            "record_types=".to_owned(),
+            "max_windows=".to_owned(),
+        ]),
+    }
 }
@@ -2,29 +2,40 @@ pub mod completion;
 pub use completion::*;
 
 mod opts;
+pub use opts::RecordFilter;
 use opts::*;
 
 use crate::{
     common_types::{CursorPosition, LazyRedraw},
     config::{get_config, WindowGridSize},
-    state::{Record, TrackList, Tracker},
+    state::{Record, TrackList, Tracker, WorkSession},
     ui::{
         grid::{open_grid, GridLayout},
+        record_mark::truncate_path,
         tab::{close_tab, open_tab},
     },
     InputError, Result,
 };
 use std::{collections::HashSet, sync::Mutex};
 
-use nvim_oxi::api::{set_current_buf, Buffer};
+use chrono::{DateTime, Local, Utc};
+use nvim_oxi::{
+    api::{call_function, set_current_buf, Buffer},
+    Array,
+};
 
 pub fn get_open(tracker: &'static Mutex<Tracker>) -> impl Fn(Option<OpenOptions>) -> Result<()> {
     move |opts: Option<OpenOptions>| {
         let OpenOptions {
             record_types,
             max_windows,
+            mode,
         } = opts.unwrap_or_default();
 
+        if mode == OpenMode::Sessions {
+            return open_work_sessions(tracker);
+        }
+
         let tracker = &mut tracker.lock()?;
         if tracker.list.is_empty() {
             return Err(InputError::NoRecords("record list is empty".to_owned()))?;
@@ -36,7 +47,7 @@ pub fn get_open(tracker: &'static Mutex<Tracker>) -> impl Fn(Option<OpenOptions>
             let iter = get_unique_bufs_priority(max_windows, &mut tracker.list)?.into_iter();
             match record_types {
                 Some(record_types) => iter
-                    .filter(|&r| record_types.iter().any(|&f| f == r.place_type.into()))
+                    .filter(|&r| record_types.iter().any(|&f| f == (&r.place_type).into()))
                     .collect(),
                 None => iter.collect(),
             }
@@ -62,6 +73,59 @@ pub fn get_open(tracker: &'static Mutex<Tracker>) -> impl Fn(Option<OpenOptions>
     }
 }
 
+/// Lists the work sessions to choose the one to jump to the start of
+fn open_work_sessions(tracker: &'static Mutex<Tracker>) -> Result<()> {
+    let lines: Vec<String> = {
+        let tracker = tracker.lock()?;
+        let sessions = tracker.work_sessions(|_| true);
+        if sessions.is_empty() {
+            Err(InputError::NoRecords(
+                "there are no work sessions to choose from".to_owned(),
+            ))?
+        }
+
+        let mut lines = Vec::from(["Work sessions:".to_owned()]);
+        for (i, s) in sessions.iter().enumerate() {
+            lines.push(format!("{}. {}", i + 1, work_session_line(&tracker, s)?));
+        }
+        lines
+    };
+
+    // Do not hold the lock while waiting for the input
+    let choice: i64 = call_function("inputlist", (Array::from_iter(lines),))?;
+    if choice < 1 {
+        return Ok(());
+    }
+
+    tracker.lock()?.goto_work_session(choice as usize - 1)
+}
+
+fn work_session_line(tracker: &Tracker, session: &WorkSession) -> Result<String> {
+    let time = |t| DateTime::<Local>::from(Into::<DateTime<Utc>>::into(t));
+    let depth = get_config().picker.filename.depth;
+
+    let mut files = Vec::<String>::new();
+    for i in session.records.iter().rev() {
+        let Some(r) = tracker.list.get(*i) else {
+            continue;
+        };
+        let name = truncate_path(&r.buf.get_name()?, depth)
+            .to_string_lossy()
+            .into_owned();
+        if !files.contains(&name) {
+            files.push(name);
+        }
+    }
+
+    Ok(format!(
+        "{} - {} ({} records) {}",
+        time(session.started).format("%Y-%m-%d %H:%M"),
+        time(session.ended).format("%H:%M"),
+        session.records.len(),
+        files.join(", ")
+    ))
+}
+
 /// HACK: we have to do the whole tab opening and buf setting part because otherwise
 /// the extmark positions will be broken if the extmarks were loaded from session management
 pub fn get_unique_bufs_priority(
@@ -161,6 +225,7 @@ mod tests {
         open(Some(OpenOptions {
             record_types: Some(vec![RecordFilter::Change]),
             max_windows: 8.try_into().unwrap(),
+            mode: OpenMode::Records,
         }))
         .unwrap();
     }
@@ -14,6 +14,18 @@ pub struct OpenOptions {
     pub record_types: Option<Vec<RecordFilter>>,
     #[serde(default = "default_max_windows")]
     pub max_windows: WindowGridSize,
+    #[serde(default)]
+    pub mode: OpenMode,
+}
+
+#[derive(Default, Deserialize, Clone, Copy, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum OpenMode {
+    /// Choose between records in a grid of windows
+    #[default]
+    Records,
+    /// Choose between work sessions from a list
+    Sessions,
 }
 
 fn default_max_windows() -> WindowGridSize {
@@ -25,6 +37,7 @@ impl Default for OpenOptions {
         Self {
             record_types: None,
             max_windows: default_max_windows(),
+            mode: OpenMode::default(),
         }
     }
 }
@@ -33,6 +46,9 @@ impl Default for OpenOptions {
 #[serde(rename_all = "snake_case")]
 pub enum RecordFilter {
     Change,
+    Bookmark,
+    Jump,
+    Quickfix,
 }
 
 impl TryFrom<&str> for RecordFilter {
@@ -41,6 +57,9 @@ impl TryFrom<&str> for RecordFilter {
     fn try_from(value: &str) -> Result<Self> {
         match value {
             "change" => Ok(RecordFilter::Change),
+            "bookmark" => Ok(RecordFilter::Bookmark),
+            "jump" => Ok(RecordFilter::Jump),
+            "quickfix" => Ok(RecordFilter::Quickfix),
             _ => Err(InputError::FunctionArguments(format!(
                 "unkwnown filter provided: {}",
                 value
@@ -49,10 +68,13 @@ impl TryFrom<&str> for RecordFilter {
     }
 }
 
-impl From<PlaceTypeRecord> for RecordFilter {
-    fn from(value: PlaceTypeRecord) -> Self {
+impl From<&PlaceTypeRecord> for RecordFilter {
+    fn from(value: &PlaceTypeRecord) -> Self {
         match value {
             PlaceTypeRecord::Change(_) => Self::Change,
+            PlaceTypeRecord::Bookmark { .. } => Self::Bookmark,
+            PlaceTypeRecord::Jump => Self::Jump,
+            PlaceTypeRecord::Quickfix => Self::Quickfix,
         }
     }
 }
@@ -75,9 +97,15 @@ impl<'a> TryFrom<CompassArgs<'a>> for OpenOptions {
             .transpose()?
             .unwrap_or_else(default_max_windows);
 
+        let mode = match value.sub_cmds.first() {
+            Some(&"sessions") => OpenMode::Sessions,
+            _ => OpenMode::Records,
+        };
+
         Ok(Self {
             record_types,
             max_windows,
+            mode,
         })
     }
 }
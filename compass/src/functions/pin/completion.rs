@@ -0,0 +1,9 @@
+use crate::viml::CompassArgs;
+
+pub fn get_pin_completion(cargs: &CompassArgs) -> Vec<String> {
+    let Some(_) = cargs.sub_cmds.first() else {
+        return Vec::from(&["toggle".to_owned()]);
+    };
+
+    Vec::from(&["toggle".to_owned()])
+}
@@ -0,0 +1,45 @@
+mod completion;
+use std::sync::Mutex;
+
+pub use completion::*;
+
+mod opts;
+use opts::*;
+
+use crate::{
+    common_types::CursorPosition, state::Tracker, ui::record_mark::recreate_mark_time, InputError,
+    Result,
+};
+
+use nvim_oxi::api::{get_current_buf, get_current_win};
+
+pub fn get_pin(tracker: &'static Mutex<Tracker>) -> impl Fn(Option<PinOptions>) -> Result<()> {
+    move |opts: Option<PinOptions>| {
+        let opts = opts.unwrap_or_default();
+
+        let mut tracker = tracker.lock()?;
+
+        match opts {
+            PinOptions::Toggle(ToggleOptions {}) => {
+                let buf_curr = get_current_buf();
+                let pos_curr: CursorPosition = get_current_win().get_cursor()?.into();
+
+                tracker.sync_cursor();
+
+                tracker.activate_first()?;
+                let Some(i) = tracker.closest_record_idx(&buf_curr, &pos_curr) else {
+                    Err(InputError::NoRecords(
+                        "no record nearby the cursor to toggle the pin of".to_owned(),
+                    ))?
+                };
+
+                let pos = tracker.list.pos;
+                if let Some(r) = tracker.list.get_mut(i) {
+                    r.set_pinned(!r.pinned, recreate_mark_time(i, pos))?;
+                }
+
+                Ok(())
+            }
+        }
+    }
+}
@@ -0,0 +1,40 @@
+use crate::{viml::CompassArgs, Error, InputError, Result};
+use macros::FromLua;
+
+use serde::Deserialize;
+
+#[derive(Deserialize, FromLua)]
+#[serde(rename_all = "snake_case")]
+pub enum PinOptions {
+    Toggle(ToggleOptions),
+}
+
+impl Default for PinOptions {
+    fn default() -> Self {
+        PinOptions::Toggle(ToggleOptions::default())
+    }
+}
+
+#[derive(Default, Deserialize)]
+pub struct ToggleOptions {}
+
+impl<'a> TryFrom<CompassArgs<'a>> for PinOptions {
+    type Error = Error;
+
+    fn try_from(value: CompassArgs<'a>) -> Result<Self> {
+        let Some(&sub) = value.sub_cmds.first() else {
+            Err(InputError::FunctionArguments(
+                "no `pin` subcommand provided".to_owned(),
+            ))?
+        };
+
+        match sub {
+            "toggle" => Ok(Self::Toggle(ToggleOptions {})),
+
+            sub => Err(InputError::FunctionArguments(format!(
+                "unknown `pin` subcommand provided: {}",
+                sub
+            )))?,
+        }
+    }
+}
@@ -1,9 +1,14 @@
 use crate::viml::CompassArgs;
 
 pub fn get_place_completion(cargs: &CompassArgs) -> Vec<String> {
-    let Some(_) = cargs.sub_cmds.first() else {
-        return Vec::from(&["change".to_owned()]);
+    let Some(first) = cargs.sub_cmds.first() else {
+        return Vec::from(&["change".to_owned(), "pin".to_owned(), "bookmark".to_owned()]);
     };
 
-    Vec::from(&["change".to_owned()])
+    match *first {
+        "change" => Vec::from(&["note=".to_owned()]),
+        "bookmark" => Vec::from(&["name=".to_owned()]),
+
+        _ => Vec::from(&["change".to_owned(), "pin".to_owned(), "bookmark".to_owned()]),
+    }
 }
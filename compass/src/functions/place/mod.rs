@@ -7,7 +7,7 @@ mod opts;
 use opts::*;
 
 use crate::{
-    state::{ChangeTypeRecord, PlaceTypeRecord, Record, TrackList, Tracker},
+    state::{ChangeTypeRecord, PlaceTypeRecord, Record, Tracker},
     ui::record_mark::RecordMarkTime,
     Result,
 };
@@ -21,54 +21,172 @@ pub fn get_place(tracker: &'static Mutex<Tracker>) -> impl Fn(Option<PlaceOption
         let mut tracker = tracker.lock()?;
 
         match opts {
-            PlaceOptions::Change(ChangeOptions {}) => {
-                let buf_curr = get_current_buf();
-                let win_curr = get_current_win();
-
-                let pos_curr = get_current_win().get_cursor()?.into();
-
-                tracker.activate_first()?;
-                let Some((i, old_record)) = tracker.list.iter_mut_from_future().enumerate().find(
-                    |(
-                        _,
-                        Record {
-                            buf, lazy_extmark, ..
-                        },
-                    )| {
-                        buf_curr == *buf && {
-                            lazy_extmark.pos(buf_curr.clone()).is_nearby(&pos_curr)
-                        }
-                    },
-                ) else {
-                    return new_change_manual_record(buf_curr, win_curr, &mut tracker.list);
-                };
-
-                old_record.update(
-                    buf_curr,
-                    PlaceTypeRecord::Change(ChangeTypeRecord::Manual(old_record.place_type.tick())),
-                    pos_curr,
-                    RecordMarkTime::PastClose,
-                )?;
-                tracker.list.make_close_past(i);
-
-                Ok(())
+            PlaceOptions::Change(ChangeOptions { note }) => place_change(&mut tracker, false, note),
+            PlaceOptions::Pin(PinOptions {}) => place_change(&mut tracker, true, None),
+            PlaceOptions::Bookmark(BookmarkOptions { name }) => place_bookmark(&mut tracker, name),
+        }
+    }
+}
+
+/// Provided note replaces the existing one, otherwise the old note is kept
+fn place_change(tracker: &mut Tracker, pin: bool, note: Option<String>) -> Result<()> {
+    let buf_curr = get_current_buf();
+    let win_curr = get_current_win();
+
+    let pos_curr = get_current_win().get_cursor()?.into();
+
+    tracker.sync_cursor();
+
+    tracker.activate_first()?;
+    let Some((i, old_record)) = tracker
+        .list
+        .iter_mut_from_future()
+        .enumerate()
+        .find(|(_, r)| {
+            r.buf == buf_curr
+                && r.follows_changes()
+                && r.lazy_extmark.pos(buf_curr.clone()).is_nearby(&pos_curr)
+        })
+    else {
+        return new_change_manual_record(buf_curr, win_curr, tracker, pin, note);
+    };
+
+    old_record.pinned = pin;
+    if note.is_some() {
+        old_record.note = note;
+    }
+    old_record.update(
+        buf_curr,
+        PlaceTypeRecord::Change(ChangeTypeRecord::Manual(old_record.place_type.tick())),
+        pos_curr,
+        RecordMarkTime::PastClose,
+    )?;
+    tracker.list.make_close_past(i);
+
+    Ok(())
+}
+
+/// Bookmark names are unique, so placing an existing one moves it to the cursor,
+/// even when it is stashed in a branch
+fn place_bookmark(tracker: &mut Tracker, name: String) -> Result<()> {
+    let buf_curr = get_current_buf();
+    let pos_curr = get_current_win().get_cursor()?.into();
+
+    tracker.sync_cursor();
+
+    tracker.activate_first()?;
+    let old_idx = tracker
+        .list
+        .iter_from_future()
+        .position(|r| r.place_type.bookmark_name() == Some(&name));
+
+    let Some(i) = old_idx else {
+        // The bookmark stashed in a branch is brought back, so that the name stays unique
+        let mut stashed = None;
+        tracker.list.retain_branches(|r, _| {
+            if stashed.is_none() && r.place_type.bookmark_name() == Some(&name) {
+                stashed = Some(r.clone());
+                return false;
             }
+            true
+        });
+
+        let record_new = match stashed {
+            Some(mut r) => {
+                r.relocate(buf_curr, pos_curr, RecordMarkTime::PastClose)?;
+                r
+            }
+            None => Record::try_new(buf_curr, PlaceTypeRecord::Bookmark { name }, &pos_curr)?,
+        };
+        return tracker.push(record_new);
+    };
+
+    if let Some(old_record) = tracker.list.get_mut(i) {
+        match old_record.buf == buf_curr {
+            true => old_record.update(
+                buf_curr,
+                PlaceTypeRecord::Bookmark { name },
+                pos_curr,
+                RecordMarkTime::PastClose,
+            )?,
+            false => old_record.relocate(buf_curr, pos_curr, RecordMarkTime::PastClose)?,
         }
     }
+    tracker.list.make_close_past(i);
+
+    Ok(())
 }
 
 fn new_change_manual_record(
     buf: Buffer,
     win: Window,
-    record_list: &mut TrackList<Record>,
+    tracker: &mut Tracker,
+    pin: bool,
+    note: Option<String>,
 ) -> Result<()> {
-    let record_new = Record::try_new(
+    let mut record_new = Record::try_new(
         buf,
         PlaceTypeRecord::Change(ChangeTypeRecord::Manual(None)),
         &win.get_cursor()?.into(),
     )?;
+    record_new.set_pinned(pin, RecordMarkTime::PastClose)?;
+    record_new.set_note(note, RecordMarkTime::PastClose)?;
+
+    tracker.push(record_new)
+}
 
-    record_list.push(record_new);
+mod tests {
+    use crate::common_types::CursorPosition;
 
-    Ok(())
+    use super::*;
+
+    #[nvim_oxi::test]
+    fn places_own_record_next_to_pinned_one() {
+        let mut tracker = Tracker::default();
+        let mut pinned = Record::try_new(
+            get_current_buf(),
+            PlaceTypeRecord::Change(ChangeTypeRecord::Tick(4.into())),
+            &CursorPosition::from((1, 0)),
+        )
+        .unwrap();
+        pinned.pinned = true;
+        tracker.list.push(pinned);
+
+        place_change(&mut tracker, false, None).unwrap();
+
+        assert_eq!(tracker.list.len(), 2);
+        assert_eq!(
+            tracker.list.iter_from_future().filter(|r| r.pinned).count(),
+            1
+        );
+    }
+
+    #[nvim_oxi::test]
+    fn brings_back_bookmark_stashed_in_branch() {
+        let mut tracker = Tracker::default();
+        tracker.list.push(
+            Record::try_new(
+                get_current_buf(),
+                PlaceTypeRecord::Bookmark {
+                    name: "main".to_owned(),
+                },
+                &CursorPosition::from((1, 0)),
+            )
+            .unwrap(),
+        );
+        tracker.list.pos = Some(0);
+        tracker.list.fork();
+
+        place_bookmark(&mut tracker, "main".to_owned()).unwrap();
+
+        assert_eq!(tracker.list.iter_branches().count(), 0);
+        assert_eq!(
+            tracker
+                .list
+                .iter_from_future()
+                .filter(|r| r.place_type.bookmark_name().is_some_and(|n| n == "main"))
+                .count(),
+            1
+        );
+    }
 }
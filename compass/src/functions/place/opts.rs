@@ -6,6 +6,8 @@ use serde::Deserialize;
 #[derive(Deserialize, FromLua)]
 pub enum PlaceOptions {
     Change(ChangeOptions),
+    Pin(PinOptions),
+    Bookmark(BookmarkOptions),
 }
 
 impl Default for PlaceOptions {
@@ -15,7 +17,18 @@ impl Default for PlaceOptions {
 }
 
 #[derive(Default, Deserialize)]
-pub struct ChangeOptions {}
+pub struct ChangeOptions {
+    #[serde(default)]
+    pub note: Option<String>,
+}
+
+#[derive(Default, Deserialize)]
+pub struct PinOptions {}
+
+#[derive(Deserialize)]
+pub struct BookmarkOptions {
+    pub name: String,
+}
 
 impl<'a> TryFrom<CompassArgs<'a>> for PlaceOptions {
     type Error = Error;
@@ -28,7 +41,30 @@ impl<'a> TryFrom<CompassArgs<'a>> for PlaceOptions {
         };
 
         match sub {
-            "change" => Ok(Self::Change(ChangeOptions {})),
+            "change" => Ok(Self::Change(ChangeOptions {
+                note: value
+                    .map_args
+                    .get("note")
+                    .filter(|n| !n.is_empty())
+                    .map(|&n| n.to_owned()),
+            })),
+            "pin" => Ok(Self::Pin(PinOptions {})),
+            "bookmark" => {
+                let name = value.map_args.get("name").copied().ok_or_else(|| {
+                    InputError::FunctionArguments(
+                        "have chosen `bookmark` but not specified the name".to_owned(),
+                    )
+                })?;
+                if name.is_empty() {
+                    Err(InputError::FunctionArguments(
+                        "bookmark name can't be empty".to_owned(),
+                    ))?
+                }
+
+                Ok(Self::Bookmark(BookmarkOptions {
+                    name: name.to_owned(),
+                }))
+            }
 
             sub => Err(InputError::FunctionArguments(format!(
                 "unknown `place` subcommand provided: {}",
@@ -2,12 +2,24 @@ use crate::viml::CompassArgs;
 
 pub fn get_pop_completion(cargs: &CompassArgs) -> Vec<String> {
     let Some(first) = cargs.sub_cmds.first() else {
-        return Vec::from(&["relative".to_owned()]);
+        return Vec::from(&["relative".to_owned(), "absolute".to_owned()]);
     };
 
     match *first {
-        "relative" => Vec::from(&["direction=".to_owned()]),
+        "relative" => Vec::from(&[
+            "direction=".to_owned(),
+            "count=".to_owned(),
+            "scope=".to_owned(),
+        ]),
 
-        _ => Vec::from(&["relative".to_owned()]),
+        "absolute" => Vec::from(&[
+            "index=".to_owned(),
+            "id=".to_owned(),
+            "time=".to_owned(),
+            "name=".to_owned(),
+            "jump=".to_owned(),
+        ]),
+
+        _ => Vec::from(&["relative".to_owned(), "absolute".to_owned()]),
     }
 }
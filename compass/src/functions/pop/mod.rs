@@ -6,7 +6,7 @@ pub use completion::*;
 mod opts;
 use opts::*;
 
-use crate::{common_types::Direction, state::Tracker, Result};
+use crate::{common_types::Direction, functions::goto::find_absolute, state::Tracker, Result};
 
 pub fn get_pop(tracker: &'static Mutex<Tracker>) -> impl Fn(Option<PopOptions>) -> Result<()> {
     move |opts: Option<PopOptions>| {
@@ -15,10 +15,19 @@ pub fn get_pop(tracker: &'static Mutex<Tracker>) -> impl Fn(Option<PopOptions>)
         let mut tracker = tracker.lock()?;
 
         match opts {
-            PopOptions::Relative(RelativeOptions { direction }) => match direction {
-                Direction::Back => tracker.pop_past(),
-                Direction::Forward => tracker.pop_future(),
+            PopOptions::Relative(RelativeOptions {
+                direction,
+                count,
+                scope,
+            }) => match direction {
+                Direction::Back => tracker.pop_past(count, scope),
+                Direction::Forward => tracker.pop_future(count, scope),
             },
+
+            PopOptions::Absolute(AbsoluteOptions { target, jump }) => {
+                let idx_record = find_absolute(&tracker, target)?;
+                tracker.pop_absolute(idx_record, jump)
+            }
         }
     }
 }
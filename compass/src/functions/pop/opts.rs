@@ -1,4 +1,9 @@
-use crate::{common_types::Direction, viml::CompassArgs, Error, InputError, Result};
+use crate::{
+    common_types::{Direction, Scope},
+    functions::goto::AbsoluteTarget,
+    viml::CompassArgs,
+    Error, InputError, Result,
+};
 use macros::FromLua;
 
 use serde::Deserialize;
@@ -7,6 +12,7 @@ use serde::Deserialize;
 #[serde(rename_all = "snake_case")]
 pub enum PopOptions {
     Relative(RelativeOptions),
+    Absolute(AbsoluteOptions),
 }
 
 impl Default for PopOptions {
@@ -19,16 +25,39 @@ impl Default for PopOptions {
 pub struct RelativeOptions {
     #[serde(default)]
     pub direction: Direction,
+    /// How many records to move by at once
+    #[serde(default = "default_count")]
+    pub count: usize,
+    #[serde(default)]
+    pub scope: Scope,
+}
+
+fn default_count() -> usize {
+    1
 }
 
 impl Default for RelativeOptions {
     fn default() -> Self {
         Self {
             direction: Direction::Back,
+            count: default_count(),
+            scope: Scope::default(),
         }
     }
 }
 
+#[derive(Debug, Deserialize)]
+pub struct AbsoluteOptions {
+    pub target: AbsoluteTarget,
+    /// Whether to jump to the record before removing it
+    #[serde(default = "default_jump")]
+    pub jump: bool,
+}
+
+fn default_jump() -> bool {
+    true
+}
+
 impl TryFrom<CompassArgs<'_>> for PopOptions {
     type Error = Error;
 
@@ -52,8 +81,42 @@ impl TryFrom<CompassArgs<'_>> for PopOptions {
                     })?
                     .try_into()
                     .map_err(InputError::EnumParse)?;
+                let count = value
+                    .map_args
+                    .get("count")
+                    .map(|s| s.parse::<usize>())
+                    .transpose()
+                    .map_err(InputError::Int)?
+                    .unwrap_or_else(default_count);
+                let scope: Scope = value
+                    .map_args
+                    .get("scope")
+                    .copied()
+                    .map(Scope::try_from)
+                    .transpose()
+                    .map_err(InputError::EnumParse)?
+                    .unwrap_or_default();
 
-                Ok(Self::Relative(RelativeOptions { direction }))
+                Ok(Self::Relative(RelativeOptions {
+                    direction,
+                    count,
+                    scope,
+                }))
+            }
+
+            "absolute" => {
+                let jump = value
+                    .map_args
+                    .get("jump")
+                    .map(|s| s.parse::<bool>())
+                    .transpose()
+                    .map_err(InputError::Bool)?
+                    .unwrap_or_else(default_jump);
+
+                Ok(Self::Absolute(AbsoluteOptions {
+                    target: (&value).try_into()?,
+                    jump,
+                }))
             }
 
             sub => Err(InputError::FunctionArguments(format!(
@@ -63,3 +126,36 @@ impl TryFrom<CompassArgs<'_>> for PopOptions {
         }
     }
 }
+
+mod tests {
+    use std::collections::HashMap;
+
+    use super::*;
+
+    #[test]
+    fn can_turn_compass_args_absolute() {
+        let mut map_args: HashMap<&str, &str> = HashMap::new();
+        map_args.insert("id", "7");
+        map_args.insert("jump", "false");
+
+        let args = CompassArgs {
+            main_cmd: "pop",
+            sub_cmds: vec!["absolute"],
+            map_args,
+        };
+
+        let got: PopOptions = args.try_into().unwrap();
+
+        match got {
+            PopOptions::Absolute(AbsoluteOptions {
+                target: AbsoluteTarget::Id(id),
+                jump,
+            }) => {
+                assert_eq!(id, 7.into());
+                assert!(!jump);
+            }
+
+            _ => panic!("got: {:?}", got),
+        }
+    }
+}
@@ -0,0 +1,5 @@
+use crate::viml::CompassArgs;
+
+pub fn get_restore_completion(_: &CompassArgs) -> Vec<String> {
+    Vec::new()
+}
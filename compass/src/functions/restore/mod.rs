@@ -0,0 +1,20 @@
+mod completion;
+use std::sync::Mutex;
+
+pub use completion::*;
+
+mod opts;
+use opts::*;
+
+use crate::{state::Tracker, Result};
+
+pub fn get_restore(
+    tracker: &'static Mutex<Tracker>,
+) -> impl Fn(Option<RestoreOptions>) -> Result<()> {
+    move |opts: Option<RestoreOptions>| {
+        let RestoreOptions {} = opts.unwrap_or_default();
+
+        let mut tracker = tracker.lock()?;
+        tracker.restore()
+    }
+}
@@ -0,0 +1,22 @@
+use crate::{viml::CompassArgs, Error, InputError, Result};
+use macros::FromLua;
+
+use serde::Deserialize;
+
+#[derive(Default, Deserialize, FromLua)]
+pub struct RestoreOptions {}
+
+impl<'a> TryFrom<CompassArgs<'a>> for RestoreOptions {
+    type Error = Error;
+
+    fn try_from(value: CompassArgs<'a>) -> Result<Self> {
+        if let Some(&sub) = value.sub_cmds.first() {
+            Err(InputError::FunctionArguments(format!(
+                "`restore` takes no subcommands, got: {}",
+                sub
+            )))?
+        };
+
+        Ok(Self {})
+    }
+}
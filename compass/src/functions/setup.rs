@@ -8,6 +8,7 @@ use crate::{
 };
 
 use anyhow::anyhow;
+use nvim_oxi::api::{notify, opts::NotifyOpts, types::LogLevel};
 
 pub fn get_setup(tracker: &'static Mutex<Tracker>) -> impl FnOnce(Option<Config>) -> Result<()> {
     move |user_conf: Option<Config>| {
@@ -18,7 +19,14 @@ pub fn get_setup(tracker: &'static Mutex<Tracker>) -> impl FnOnce(Option<Config>
             let mut tracker = tracker.lock()?;
             if conf.persistence.enable {
                 if let Some(path) = &conf.persistence.path {
-                    tracker.load_state(path)?;
+                    // Keep going with an empty history instead of failing the whole setup
+                    if let Err(e) = tracker.load_state(path) {
+                        let _ = notify(
+                            &e.to_string(),
+                            LogLevel::Error,
+                            &NotifyOpts::builder().build(),
+                        );
+                    }
                 } else {
                     return Err(anyhow!(
                         "tracker persistence enabled yet no specified load state path found"
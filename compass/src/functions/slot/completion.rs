@@ -0,0 +1,22 @@
+use crate::{state::Tracker, viml::CompassArgs};
+use std::sync::Mutex;
+
+pub fn get_slot_completion(cargs: &CompassArgs, tracker: &'static Mutex<Tracker>) -> Vec<String> {
+    let Some(first) = cargs.sub_cmds.first() else {
+        return Vec::from(&["set".to_owned(), "clear".to_owned()]);
+    };
+
+    match *first {
+        "set" => Vec::from(&["n=".to_owned()]),
+        "clear" => {
+            let mut completion = Vec::from(&["n=".to_owned()]);
+            if let Ok(tracker) = tracker.lock() {
+                completion.extend(tracker.slot_completion());
+            }
+
+            completion
+        }
+
+        _ => Vec::from(&["set".to_owned(), "clear".to_owned()]),
+    }
+}
@@ -0,0 +1,57 @@
+mod completion;
+use std::sync::Mutex;
+
+pub use completion::*;
+
+mod opts;
+use opts::*;
+pub use opts::{deserialize_slot, parse_slot};
+
+use crate::{common_types::CursorPosition, state::Tracker, InputError, Result};
+
+use nvim_oxi::api::{get_current_buf, get_current_win};
+
+pub fn get_slot(tracker: &'static Mutex<Tracker>) -> impl Fn(Option<SlotOptions>) -> Result<()> {
+    move |opts: Option<SlotOptions>| {
+        let Some(opts) = opts else {
+            Err(InputError::FunctionArguments(
+                "no `slot` subcommand provided".to_owned(),
+            ))?
+        };
+
+        let mut tracker = tracker.lock()?;
+
+        match opts {
+            SlotOptions::Set(SetOptions { n }) => {
+                let i = closest_record_idx(&mut tracker)?;
+                tracker.bind_slot(i, Some(n))
+            }
+
+            SlotOptions::Clear(ClearOptions { n: Some(n) }) => {
+                let i = tracker
+                    .list
+                    .iter_from_future()
+                    .position(|r| r.slot == Some(n))
+                    .ok_or_else(|| {
+                        InputError::NoRecords(format!("no record bound to slot {}", n))
+                    })?;
+                tracker.bind_slot(i, None)
+            }
+
+            SlotOptions::Clear(ClearOptions { n: None }) => {
+                let i = closest_record_idx(&mut tracker)?;
+                tracker.bind_slot(i, None)
+            }
+        }
+    }
+}
+
+fn closest_record_idx(tracker: &mut Tracker) -> Result<usize> {
+    let buf_curr = get_current_buf();
+    let pos_curr: CursorPosition = get_current_win().get_cursor()?.into();
+
+    tracker.activate_first()?;
+    Ok(tracker
+        .closest_record_idx(&buf_curr, &pos_curr)
+        .ok_or_else(|| InputError::NoRecords("no record nearby the cursor to slot".to_owned()))?)
+}
@@ -0,0 +1,140 @@
+use crate::{viml::CompassArgs, Error, InputError, Result};
+use macros::FromLuaRequired;
+
+use serde::{Deserialize, Deserializer};
+
+/// Slot numbers have to fit into the two sign columns
+const MAX_SLOT: u8 = 99;
+
+#[derive(Debug, Deserialize, FromLuaRequired)]
+#[serde(rename_all = "snake_case")]
+pub enum SlotOptions {
+    Set(SetOptions),
+    Clear(ClearOptions),
+}
+
+#[derive(Debug, Deserialize)]
+pub struct SetOptions {
+    #[serde(deserialize_with = "deserialize_slot")]
+    pub n: u8,
+}
+
+#[derive(Debug, Default, Deserialize)]
+pub struct ClearOptions {
+    /// Clear the slot of the record nearby the cursor when not provided
+    #[serde(default, deserialize_with = "deserialize_opt_slot")]
+    pub n: Option<u8>,
+}
+
+fn check_slot(n: u64) -> Result<u8> {
+    match u8::try_from(n) {
+        Ok(n) if n <= MAX_SLOT => Ok(n),
+        _ => Err(InputError::FunctionArguments(format!(
+            "slot out of range, has to be at most {}, got: {}",
+            MAX_SLOT, n
+        )))?,
+    }
+}
+
+pub fn parse_slot(s: &str) -> Result<u8> {
+    check_slot(s.parse().map_err(InputError::Int)?)
+}
+
+pub fn deserialize_slot<'de, D>(deserializer: D) -> core::result::Result<u8, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    check_slot(u64::deserialize(deserializer)?).map_err(serde::de::Error::custom)
+}
+
+fn deserialize_opt_slot<'de, D>(deserializer: D) -> core::result::Result<Option<u8>, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    Option::<u64>::deserialize(deserializer)?
+        .map(check_slot)
+        .transpose()
+        .map_err(serde::de::Error::custom)
+}
+
+impl<'a> TryFrom<CompassArgs<'a>> for SlotOptions {
+    type Error = Error;
+
+    fn try_from(value: CompassArgs<'a>) -> Result<Self> {
+        let Some(&sub) = value.sub_cmds.first() else {
+            Err(InputError::FunctionArguments(
+                "no `slot` subcommand provided".to_owned(),
+            ))?
+        };
+
+        let n = value
+            .map_args
+            .get("n")
+            .copied()
+            .map(parse_slot)
+            .transpose()?;
+
+        match sub {
+            "set" => Ok(Self::Set(SetOptions {
+                n: n.ok_or_else(|| {
+                    InputError::FunctionArguments(
+                        "have chosen `set` but not specified the slot number".to_owned(),
+                    )
+                })?,
+            })),
+            "clear" => Ok(Self::Clear(ClearOptions { n })),
+
+            sub => Err(InputError::FunctionArguments(format!(
+                "unknown `slot` subcommand provided: {}",
+                sub
+            )))?,
+        }
+    }
+}
+
+mod tests {
+    use std::collections::HashMap;
+
+    use super::*;
+
+    #[test]
+    fn can_turn_compass_args_set() {
+        let mut map_args: HashMap<&str, &str> = HashMap::new();
+        map_args.insert("n", "3");
+
+        let args = CompassArgs {
+            main_cmd: "slot",
+            sub_cmds: vec!["set"],
+            map_args,
+        };
+
+        let got: SlotOptions = args.try_into().unwrap();
+
+        match got {
+            SlotOptions::Set(SetOptions { n }) => assert_eq!(n, 3),
+
+            _ => panic!("got: {:?}", got),
+        }
+    }
+
+    #[test]
+    fn rejects_slots_out_of_range() {
+        assert_eq!(parse_slot("99").unwrap(), 99);
+        assert!(parse_slot("100").is_err());
+        assert!(parse_slot("300").is_err());
+    }
+
+    #[test]
+    fn rejects_slots_not_fitting_the_sign() {
+        let mut map_args: HashMap<&str, &str> = HashMap::new();
+        map_args.insert("n", "100");
+
+        let args = CompassArgs {
+            main_cmd: "slot",
+            sub_cmds: vec!["set"],
+            map_args,
+        };
+
+        assert!(TryInto::<SlotOptions>::try_into(args).is_err());
+    }
+}
@@ -33,6 +33,10 @@ pub struct RecordHighlightList<'a> {
     future: RecordHighlight<'a>,
     #[serde(borrow)]
     close_future: RecordHighlight<'a>,
+    #[serde(borrow)]
+    stale: RecordHighlight<'a>,
+    #[serde(borrow)]
+    note: OptsHighlight<'a>,
 }
 
 pub struct RecordHighlightNames {
@@ -61,6 +65,17 @@ impl RecordHighlightList<'_> {
             },
         }
     }
+
+    pub fn stale_hl_names() -> RecordHighlightNames {
+        RecordHighlightNames {
+            mark: "CompassRecordStale",
+            sign: "CompassRecordStaleSign",
+        }
+    }
+
+    pub fn note_hl_name() -> &'static str {
+        "CompassRecordNote"
+    }
 }
 
 impl Default for RecordHighlightList<'_> {
@@ -91,6 +106,16 @@ impl Default for RecordHighlightList<'_> {
                     .build(),
                 sign: OptsHighlight::builder().fg("DarkCyan").gui("bold").build(),
             },
+
+            stale: RecordHighlight {
+                mark: OptsHighlight::builder()
+                    .fg("Gray")
+                    .gui("strikethrough")
+                    .build(),
+                sign: OptsHighlight::builder().fg("Gray").gui("bold").build(),
+            },
+
+            note: OptsHighlight::builder().fg("Gray").gui("italic").build(),
         }
     }
 }
@@ -123,7 +148,7 @@ impl HintHighlightList<'_> {
                 mark: "CompassHintOpen",
                 path: "CompassHintOpenPath",
             },
-            GridLayout::Follow => HintHighlightNames {
+            GridLayout::Follow | GridLayout::Move => HintHighlightNames {
                 mark: "CompassHintFollow",
                 path: "CompassHintFollowPath",
             },
@@ -214,18 +239,28 @@ impl<'a> Iterator for IterHighlightList<'a> {
             )),
 
             8 => Some((
+                RecordHighlightList::stale_hl_names().mark,
+                &self.hls.tracks.stale.mark,
+            )),
+            9 => Some((
+                RecordHighlightList::stale_hl_names().sign,
+                &self.hls.tracks.stale.sign,
+            )),
+            10 => Some((RecordHighlightList::note_hl_name(), &self.hls.tracks.note)),
+
+            11 => Some((
                 HintHighlightList::hint_hl_names(GridLayout::Open).mark,
                 &self.hls.hints.open.label,
             )),
-            9 => Some((
+            12 => Some((
                 HintHighlightList::hint_hl_names(GridLayout::Open).path,
                 &self.hls.hints.open.path,
             )),
-            10 => Some((
+            13 => Some((
                 HintHighlightList::hint_hl_names(GridLayout::Follow).mark,
                 &self.hls.hints.follow.label,
             )),
-            11 => Some((
+            14 => Some((
                 HintHighlightList::hint_hl_names(GridLayout::Follow).path,
                 &self.hls.hints.follow.path,
             )),
@@ -37,6 +37,11 @@ impl Frecency {
         }
     }
 
+    pub fn created_timestamp(&self) -> Timestamp {
+        // Should never panic since there must always be at least a single record
+        self.vec.first().unwrap().timestamp
+    }
+
     pub fn latest_timestamp(&self) -> Timestamp {
         // Should never panic since there must always be at least a single record
         self.vec.last().unwrap().timestamp
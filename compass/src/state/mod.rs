@@ -1,7 +1,7 @@
 pub mod frecency;
 
 mod record;
-pub use record::{ChangeTypeRecord, PlaceTypeRecord, Record, Tick};
+pub use record::{ChangeTypeRecord, PlaceTypeRecord, Record, RecordId, Tick};
 
 mod session;
 use session::*;
@@ -12,5 +12,14 @@ pub use tracker::Tracker;
 mod track_list;
 pub use track_list::TrackList;
 
+mod workspace;
+pub use workspace::Workspace;
+
+mod work_session;
+pub use work_session::WorkSession;
+
 mod worker;
 pub use worker::Worker;
+
+mod vim_lists;
+pub use vim_lists::*;
@@ -1,26 +1,48 @@
 use crate::{
-    common_types::{CursorPosition, CursorRange, Extmark},
+    common_types::{CursorPosition, CursorRange, Extmark, Scope, Timestamp},
+    config::get_config,
     state::{
         frecency::{Frecency, FrecencyScore, FrecencyType, FrecencyWeight},
         track_list::IndicateCloseness,
     },
-    ui::record_mark::{create_record_mark, update_record_mark, RecordMarkTime},
+    ui::record_mark::{create_record_mark, update_record_mark, RecordMarkStyle, RecordMarkTime},
     Result,
 };
-use std::{fmt::Display, time::Instant};
+use std::{
+    collections::HashSet,
+    fmt::Display,
+    sync::{
+        atomic::{AtomicU64, Ordering},
+        Mutex,
+    },
+    time::Instant,
+};
 
 use bitcode::{Decode, Encode};
-use nvim_oxi::api::{command, set_current_buf, Buffer, Window};
+use chrono::Utc;
+use nvim_oxi::api::{command, get_current_buf, get_current_win, set_current_buf, Buffer, Window};
 use serde::Deserialize;
 
-use super::track_list::{Active, Mark};
+use super::track_list::{Active, Identify, Mark};
 
 #[derive(Clone, Debug, PartialEq, Eq, Hash)]
 pub struct Record {
+    pub id: RecordId,
     pub buf: Buffer,
+    /// Window the record was created in, unknown for the restored ones
+    pub win: Option<Window>,
     pub place_type: PlaceTypeRecord,
     pub lazy_extmark: LazyExtmark,
     pub frecency: Frecency,
+    /// Pinned records are never merged, moved or evicted automatically
+    pub pinned: bool,
+    pub note: Option<String>,
+    /// Numbered slot the record is bound to, unique across the records.
+    /// Slotted records are never merged or evicted automatically
+    pub slot: Option<u8>,
+    /// Text of the marked line at the moment the mark was placed
+    pub captured_line: Option<String>,
+    pub stale_since: Option<Timestamp>,
 }
 
 #[derive(Clone, Debug, PartialEq, Eq, Hash)]
@@ -55,9 +77,16 @@ impl LazyExtmark {
     }
 }
 
-#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, Decode, Encode)]
+#[derive(Clone, Debug, PartialEq, Eq, Hash, Decode, Encode)]
 pub enum PlaceTypeRecord {
     Change(ChangeTypeRecord),
+    Bookmark {
+        name: String,
+    },
+    /// Taken from Vim's jumplist
+    Jump,
+    /// Taken from the quickfix or a location list
+    Quickfix,
 }
 
 #[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, Decode, Encode, Deserialize)]
@@ -76,6 +105,37 @@ impl From<i32> for Tick {
     }
 }
 
+/// Identifies a record across sessions, unlike buffer handles or timestamps
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, Decode, Encode, Deserialize)]
+#[serde(transparent)]
+pub struct RecordId(pub u64);
+
+static NEXT_RECORD_ID: AtomicU64 = AtomicU64::new(1);
+
+impl RecordId {
+    pub fn next() -> Self {
+        Self(NEXT_RECORD_ID.fetch_add(1, Ordering::Relaxed))
+    }
+
+    /// Makes sure ids handed out later never collide with this restored one
+    pub fn reserve(self) -> Self {
+        NEXT_RECORD_ID.fetch_max(self.0 + 1, Ordering::Relaxed);
+        self
+    }
+}
+
+impl Display for RecordId {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
+
+impl From<u64> for RecordId {
+    fn from(value: u64) -> Self {
+        Self(value)
+    }
+}
+
 #[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, Decode, Encode)]
 pub enum ChangeTypeRecord {
     Tick(Tick),
@@ -84,13 +144,25 @@ pub enum ChangeTypeRecord {
 }
 
 impl PlaceTypeRecord {
-    pub fn tick(self) -> Option<Tick> {
+    pub fn tick(&self) -> Option<Tick> {
         match self {
-            Self::Change(c) => match c {
+            Self::Change(c) => match *c {
                 ChangeTypeRecord::Tick(t) => Some(t),
                 ChangeTypeRecord::Manual(t) => t,
                 ChangeTypeRecord::Restored => None,
             },
+            Self::Bookmark { .. } | Self::Jump | Self::Quickfix => None,
+        }
+    }
+
+    pub fn is_bookmark(&self) -> bool {
+        matches!(self, Self::Bookmark { .. })
+    }
+
+    pub fn bookmark_name(&self) -> Option<&str> {
+        match self {
+            Self::Bookmark { name } => Some(name),
+            Self::Change(_) | Self::Jump | Self::Quickfix => None,
         }
     }
 }
@@ -101,15 +173,55 @@ impl From<Tick> for PlaceTypeRecord {
     }
 }
 
+/// Returns the text of a 1,0 indexed position's line, unless it is blank
+fn capture_line(buf: &Buffer, &CursorPosition { line, .. }: &CursorPosition) -> Option<String> {
+    let line = line.checked_sub(1)?;
+    let str_line = buf
+        .get_lines(line..=line, true)
+        .ok()?
+        .next()
+        .map(|s| s.to_string())?;
+
+    match str_line.trim().is_empty() {
+        true => None,
+        false => Some(str_line),
+    }
+}
+
+/// Whether the line was rewritten to the point where none of its original words are left
+fn is_rewritten(captured: &str, current: &str) -> bool {
+    fn words(s: &str) -> HashSet<&str> {
+        s.split(|c: char| !c.is_alphanumeric() && c != '_')
+            .filter(|w| !w.is_empty())
+            .collect()
+    }
+
+    let captured_words = words(captured);
+    if captured_words.is_empty() {
+        return captured.trim() != current.trim();
+    }
+
+    captured_words.is_disjoint(&words(current))
+}
+
 impl Record {
     pub fn try_new(buf: Buffer, place_type: PlaceTypeRecord, pos: &CursorPosition) -> Result<Self> {
-        let extmark = create_record_mark(buf.clone(), &pos.into(), RecordMarkTime::PastClose)?;
+        let extmark =
+            create_record_mark(buf.clone(), &pos.into(), RecordMarkTime::PastClose.into())?;
+        let captured_line = capture_line(&buf, &extmark.pos(buf.clone()));
 
         Ok(Self {
+            id: RecordId::next(),
             buf,
+            win: Some(get_current_win()),
             place_type,
             lazy_extmark: LazyExtmark::Loaded(extmark),
             frecency: Frecency::new(),
+            pinned: false,
+            note: None,
+            slot: None,
+            captured_line,
+            stale_since: None,
         })
     }
 
@@ -119,26 +231,114 @@ impl Record {
         pos: CursorPosition,
     ) -> Result<Self> {
         Ok(Self {
+            id: RecordId::next(),
             buf,
+            win: Some(get_current_win()),
             place_type,
             lazy_extmark: LazyExtmark::Inactive((pos, RecordMarkTime::PastClose, Instant::now())),
             frecency: Frecency::new(),
+            pinned: false,
+            note: None,
+            slot: None,
+            captured_line: None,
+            stale_since: None,
         })
     }
 
+    /// Creates a record whose mark is placed once its buffer gets visited,
+    /// the same way as for the ones restored from a session
+    pub fn new_unloaded(buf: Buffer, place_type: PlaceTypeRecord, pos: CursorPosition) -> Self {
+        Self {
+            id: RecordId::next(),
+            buf,
+            win: None,
+            place_type,
+            lazy_extmark: LazyExtmark::Unloaded((pos, RecordMarkTime::PastClose)),
+            frecency: Frecency::new(),
+            pinned: false,
+            note: None,
+            slot: None,
+            captured_line: None,
+            stale_since: None,
+        }
+    }
+
+    pub fn in_scope(&self, scope: Scope, buf: &Buffer, win: &Window) -> bool {
+        match scope {
+            Scope::Global => true,
+            Scope::Buf => &self.buf == buf,
+            // Restored and imported records do not know their window, the buffer is the closest match
+            Scope::Win => match &self.win {
+                Some(w) => w == win,
+                None => &self.buf == buf,
+            },
+        }
+    }
+
+    /// Whether the changes nearby move the record to them rather than getting records of their own,
+    /// pinned records stay where they were placed and bookmarks are only moved by name
+    pub fn follows_changes(&self) -> bool {
+        !self.pinned && !self.place_type.is_bookmark()
+    }
+
+    pub fn mark_style(&self, time: RecordMarkTime) -> RecordMarkStyle {
+        RecordMarkStyle {
+            time,
+            stale: self.stale_since.is_some(),
+            pinned: self.pinned,
+            note: self.note.as_deref(),
+            slot: self.slot,
+        }
+    }
+
+    pub fn set_pinned(&mut self, pinned: bool, time: RecordMarkTime) -> Result<()> {
+        self.pinned = pinned;
+        self.redraw_mark(time)
+    }
+
+    pub fn set_note(&mut self, note: Option<String>, time: RecordMarkTime) -> Result<()> {
+        self.note = note;
+        self.redraw_mark(time)
+    }
+
+    pub fn set_slot(&mut self, slot: Option<u8>, time: RecordMarkTime) -> Result<()> {
+        self.slot = slot;
+        self.redraw_mark(time)
+    }
+
+    /// Applies changes of the record's style to its mark if it is placed
+    fn redraw_mark(&self, time: RecordMarkTime) -> Result<()> {
+        match &self.lazy_extmark {
+            LazyExtmark::Loaded(e) => update_record_mark(
+                e,
+                self.buf.clone(),
+                &e.get_range(self.buf.clone()),
+                self.mark_style(time),
+            ),
+            LazyExtmark::Unloaded(_) | LazyExtmark::Inactive(_) => Ok(()),
+        }
+    }
+
     fn get_or_init_extmark(&mut self) -> Result<Extmark> {
         Ok(match &self.lazy_extmark {
             LazyExtmark::Loaded(e) => e.clone(),
             LazyExtmark::Unloaded((p, t)) => {
-                let extmark =
-                    create_record_mark(self.buf.clone(), &Into::<CursorRange>::into(p), *t)?;
+                let extmark = create_record_mark(
+                    self.buf.clone(),
+                    &Into::<CursorRange>::into(p),
+                    self.mark_style(*t),
+                )?;
                 self.lazy_extmark = LazyExtmark::Loaded(extmark.clone());
 
                 extmark
             }
             LazyExtmark::Inactive((p, t, _)) => {
-                let extmark =
-                    create_record_mark(self.buf.clone(), &Into::<CursorRange>::into(p), *t)?;
+                let extmark = create_record_mark(
+                    self.buf.clone(),
+                    &Into::<CursorRange>::into(p),
+                    self.mark_style(*t),
+                )?;
+                self.captured_line = capture_line(&self.buf, &extmark.pos(self.buf.clone()));
                 self.lazy_extmark = LazyExtmark::Loaded(extmark.clone());
 
                 extmark
@@ -153,21 +353,32 @@ impl Record {
         pos: CursorPosition,
         time: RecordMarkTime,
     ) -> Result<()> {
+        self.stale_since = None;
         match &self.lazy_extmark {
-            LazyExtmark::Loaded(e) => {
-                update_record_mark(e, buf.clone(), &Into::<CursorRange>::into(&pos), time)?
-            }
+            LazyExtmark::Loaded(e) => update_record_mark(
+                e,
+                buf.clone(),
+                &Into::<CursorRange>::into(&pos),
+                self.mark_style(time),
+            )?,
             LazyExtmark::Unloaded(_) => {
-                let extmark =
-                    create_record_mark(self.buf.clone(), &Into::<CursorRange>::into(&pos), time)?;
+                let extmark = create_record_mark(
+                    self.buf.clone(),
+                    &Into::<CursorRange>::into(&pos),
+                    self.mark_style(time),
+                )?;
                 self.lazy_extmark = LazyExtmark::Loaded(extmark.clone());
             }
             LazyExtmark::Inactive(_) => {
-                let extmark =
-                    create_record_mark(self.buf.clone(), &Into::<CursorRange>::into(&pos), time)?;
+                let extmark = create_record_mark(
+                    self.buf.clone(),
+                    &Into::<CursorRange>::into(&pos),
+                    self.mark_style(time),
+                )?;
                 self.lazy_extmark = LazyExtmark::Loaded(extmark.clone());
             }
         };
+        self.captured_line = capture_line(&self.buf, &self.lazy_extmark.pos(self.buf.clone()));
 
         self.place_type = place_type;
         self.frecency.add_record(FrecencyType::Update);
@@ -175,6 +386,31 @@ impl Record {
         Ok(())
     }
 
+    /// Moves the mark to the position, possibly in another buffer,
+    /// keeping everything else about the record
+    pub fn relocate(
+        &mut self,
+        buf: Buffer,
+        pos: CursorPosition,
+        time: RecordMarkTime,
+    ) -> Result<()> {
+        self.lazy_extmark.delete(self.buf.clone())?;
+        self.stale_since = None;
+
+        let extmark = create_record_mark(
+            buf.clone(),
+            &Into::<CursorRange>::into(&pos),
+            self.mark_style(time),
+        )?;
+        self.captured_line = capture_line(&buf, &extmark.pos(buf.clone()));
+        self.lazy_extmark = LazyExtmark::Loaded(extmark);
+        self.buf = buf;
+        self.win = Some(get_current_win());
+        self.frecency.add_record(FrecencyType::Update);
+
+        Ok(())
+    }
+
     pub fn deact_update(
         &mut self,
         buf: Buffer,
@@ -187,21 +423,22 @@ impl Record {
         }
 
         self.lazy_extmark = LazyExtmark::Inactive((pos, time, Instant::now()));
+        self.stale_since = None;
         self.place_type = place_type;
         self.frecency.add_record(FrecencyType::Update);
 
         Ok(())
     }
 
-    pub fn delete(&mut self) -> Result<()> {
-        self.get_or_init_extmark()?.delete(self.buf.clone())
-    }
-
     fn set_time(&mut self, time: RecordMarkTime) {
         match &self.lazy_extmark {
             LazyExtmark::Loaded(e) => {
-                let _ =
-                    update_record_mark(e, self.buf.clone(), &e.get_range(self.buf.clone()), time);
+                let _ = update_record_mark(
+                    e,
+                    self.buf.clone(),
+                    &e.get_range(self.buf.clone()),
+                    self.mark_style(time),
+                );
             }
             LazyExtmark::Unloaded((p, _)) => {
                 self.lazy_extmark = LazyExtmark::Unloaded((p.clone(), time));
@@ -213,24 +450,46 @@ impl Record {
     }
 
     pub fn load_extmark(&mut self) -> Result<()> {
-        match &self.lazy_extmark {
-            LazyExtmark::Unloaded((p, t)) => {
-                let extmark =
-                    create_record_mark(self.buf.clone(), &Into::<CursorRange>::into(p), *t)?;
-                self.lazy_extmark = LazyExtmark::Loaded(extmark.clone());
+        self.get_or_init_extmark().map(|_| ())
+    }
 
-                Ok(())
-            }
-            LazyExtmark::Inactive((p, t, _)) => {
-                let extmark =
-                    create_record_mark(self.buf.clone(), &Into::<CursorRange>::into(p), *t)?;
-                self.lazy_extmark = LazyExtmark::Loaded(extmark.clone());
+    /// Removes the mark from the buffer while remembering its position
+    pub fn unload_extmark(&mut self) -> Result<()> {
+        if let LazyExtmark::Loaded(e) = &self.lazy_extmark {
+            let pos = e.pos(self.buf.clone());
+            e.delete(self.buf.clone())?;
+            self.lazy_extmark = LazyExtmark::Unloaded((pos, RecordMarkTime::Past));
+        }
 
-                Ok(())
-            }
+        Ok(())
+    }
 
-            LazyExtmark::Loaded(_) => Ok(()),
+    /// Compares the marked line against the one captured on placing the mark
+    /// and toggles the stale highlighting when the state changes
+    pub fn check_stale(&mut self, time: RecordMarkTime) -> Result<()> {
+        let LazyExtmark::Loaded(e) = &self.lazy_extmark else {
+            return Ok(());
+        };
+        let Some(captured) = &self.captured_line else {
+            return Ok(());
+        };
+
+        let pos = e.pos(self.buf.clone());
+        let stale = capture_line(&self.buf, &pos).is_none_or(|curr| is_rewritten(captured, &curr));
+        if stale == self.stale_since.is_some() {
+            return Ok(());
         }
+
+        self.stale_since = match stale {
+            true => Some(Utc::now().into()),
+            false => None,
+        };
+        update_record_mark(
+            e,
+            self.buf.clone(),
+            &Into::<CursorRange>::into(&pos),
+            self.mark_style(time),
+        )
     }
 }
 
@@ -255,15 +514,32 @@ impl FrecencyScore for Record {
     }
 }
 
+/// Buffer handle and cursor position where the latest jump to a record has ended
+static LATEST_LANDING: Mutex<Option<(i32, CursorPosition)>> = Mutex::new(None);
+
 impl Mark for Record {
     fn jump(&mut self, mut win: Window) -> Result<()> {
-        // Leave an entry in the jumplist
-        command("normal! m'")?;
+        let conf = &get_config().tracker.jumplist;
+        if conf.leave_entry {
+            let from: (i32, CursorPosition) =
+                (get_current_buf().handle(), win.get_cursor()?.into());
+            let continues_steps = LATEST_LANDING
+                .lock()
+                .is_ok_and(|l| l.as_ref() == Some(&from));
+            // Leave an entry in the jumplist for `<C-o>` to return to
+            if !(conf.collapse_steps && continues_steps) {
+                command("normal! m'")?;
+            }
+        }
 
         let CursorPosition { line, col } = self.get_or_init_extmark()?.pos(self.buf.clone());
         set_current_buf(&self.buf)?;
         win.set_cursor(line, col)?;
 
+        if let Ok(mut l) = LATEST_LANDING.lock() {
+            *l = Some((self.buf.handle(), CursorPosition { line, col }));
+        }
+
         Ok(())
     }
 }
@@ -274,6 +550,12 @@ impl Active for Record {
     }
 }
 
+impl Identify for Record {
+    fn id(&self) -> RecordId {
+        self.id
+    }
+}
+
 mod tests {
     use core::panic;
 
@@ -283,6 +565,37 @@ mod tests {
 
     use nvim_oxi::api::{get_current_buf, opts::GetExtmarksOpts, types::ExtmarkPosition};
 
+    #[test]
+    fn only_unpinned_changes_follow_changes() {
+        let mut change = Record::new_unloaded(
+            1.into(),
+            PlaceTypeRecord::Change(ChangeTypeRecord::Tick(4.into())),
+            (1, 0).into(),
+        );
+        let bookmark = Record::new_unloaded(
+            1.into(),
+            PlaceTypeRecord::Bookmark {
+                name: "main".to_owned(),
+            },
+            (1, 0).into(),
+        );
+
+        assert!(change.follows_changes());
+        assert!(!bookmark.follows_changes());
+        change.pinned = true;
+        assert!(!change.follows_changes());
+    }
+
+    #[nvim_oxi::test]
+    fn detects_rewritten_line() {
+        assert!(!is_rewritten(
+            "let foo = bar(baz);",
+            "    let foo = bar(qux);"
+        ));
+        assert!(is_rewritten("let foo = bar(baz);", "return None;"));
+        assert!(is_rewritten("}", "{"));
+    }
+
     #[nvim_oxi::test]
     fn can_create_record_in_current_buffer() {
         let buf = get_current_buf();
@@ -309,4 +622,18 @@ mod tests {
                     _ => panic!(""),
                 })));
     }
+
+    #[test]
+    fn win_scope_falls_back_to_buf_without_window() {
+        let (buf, other_buf) = (Buffer::from(1), Buffer::from(2));
+        let (win, other_win) = (Window::from(1000), Window::from(1001));
+
+        let mut record = Record::new_unloaded(buf.clone(), PlaceTypeRecord::Jump, (1, 0).into());
+        assert!(record.in_scope(Scope::Win, &buf, &win));
+        assert!(!record.in_scope(Scope::Win, &other_buf, &win));
+
+        record.win = Some(other_win.clone());
+        assert!(!record.in_scope(Scope::Win, &buf, &win));
+        assert!(record.in_scope(Scope::Win, &other_buf, &other_win));
+    }
 }
@@ -1,7 +1,10 @@
 mod data_session;
 pub use data_session::*;
 
-use crate::{state::Record, state::TrackList, Result};
+mod migration;
+use migration::SessionV1;
+
+use crate::Result;
 use std::{
     fs::File,
     io::{BufReader, BufWriter, Read, Write},
@@ -20,15 +23,27 @@ pub fn save_session(data: Session, path: &Path) -> Result<()> {
     Ok(())
 }
 
-pub fn load_session(path: &Path) -> Result<TrackList<Record>> {
+pub fn load_session(path: &Path) -> Result<Session> {
     let file = File::open(path).map_err(|e| anyhow!("{e}"))?;
     let mut bytes = Vec::new();
     BufReader::new(file)
         .read_to_end(&mut bytes)
         .map_err(|e| anyhow!("{e}"))?;
-    let session: Session = bitcode::decode(&bytes).map_err(|e| anyhow!("{e}"))?;
 
-    session.try_into()
+    decode_session(&bytes)
+}
+
+/// Decodes the session in the current layout, migrating it when it is in one of the previous ones
+fn decode_session(bytes: &[u8]) -> Result<Session> {
+    if let Ok(session) = bitcode::decode::<Session>(bytes) {
+        if session.version == Version::default() {
+            return Ok(session);
+        }
+    }
+
+    let session: SessionV1 = bitcode::decode(bytes).map_err(|e| anyhow!("{e}"))?;
+
+    Ok(session.into())
 }
 
 mod tests {
@@ -36,7 +51,7 @@ mod tests {
 
     use nvim_oxi::api::get_current_buf;
 
-    use crate::state::{ChangeTypeRecord, PlaceTypeRecord};
+    use crate::state::{ChangeTypeRecord, PlaceTypeRecord, Record, TrackList};
 
     #[nvim_oxi::test]
     fn can_save_and_load_session() {
@@ -58,9 +73,22 @@ mod tests {
         let mut path = std::env::temp_dir();
         path.push("compass_session_load_test_file");
 
-        save_session(Session::try_from(&list).unwrap(), &path).unwrap();
+        let session = Session {
+            data: (&list).try_into().unwrap(),
+            name: "main".to_owned(),
+            workspaces: Vec::from([PersistentWorkspace {
+                name: "other".to_owned(),
+                data: (&TrackList::default()).try_into().unwrap(),
+            }]),
+            ..Default::default()
+        };
+        save_session(session, &path).unwrap();
+
+        let session = load_session(&path).unwrap();
+        assert_eq!(session.name, "main");
+        assert_eq!(session.workspaces.first().unwrap().name, "other");
 
-        let got = load_session(&path).unwrap();
+        let got: TrackList<Record> = session.data.try_into().unwrap();
         let mut want = list.iter_from_future();
         for r in got.iter_from_future() {
             let w = want.next().unwrap();
@@ -1,10 +1,10 @@
 use crate::{
-    common_types::CursorPosition,
+    common_types::{CursorPosition, Timestamp},
     state::{
-        frecency::Frecency, record::LazyExtmark, ChangeTypeRecord, PlaceTypeRecord, Record,
-        TrackList,
+        frecency::Frecency, record::LazyExtmark, track_list::Branch, ChangeTypeRecord,
+        PlaceTypeRecord, Record, RecordId, TrackList, Tracker,
     },
-    ui::record_mark::recreate_mark_time,
+    ui::record_mark::{recreate_mark_time, RecordMarkTime},
     Error, Result,
 };
 
@@ -14,6 +14,16 @@ use nvim_oxi::api::Buffer;
 #[derive(Decode, Encode, Default)]
 pub struct Session {
     pub version: Version,
+    /// The list in use
+    pub data: DataSession,
+    pub name: String,
+    /// Lists set aside
+    pub workspaces: Vec<PersistentWorkspace>,
+}
+
+#[derive(Decode, Encode)]
+pub struct PersistentWorkspace {
+    pub name: String,
     pub data: DataSession,
 }
 
@@ -21,20 +31,38 @@ pub struct Session {
 pub struct DataSession {
     pub pos: Option<usize>,
     pub records: Vec<PersistentRecord>,
+    /// Flattened tree of branches with parents always preceding their children
+    pub branches: Vec<PersistentBranch>,
+}
+
+#[derive(Decode, Encode)]
+pub struct PersistentBranch {
+    /// Index of the branch this one was split off, none if split off the current line
+    pub parent: Option<usize>,
+    pub fork: Option<RecordId>,
+    pub records: Vec<PersistentRecord>,
 }
 
 #[derive(Decode, Encode)]
 pub struct PersistentRecord {
+    pub id: RecordId,
     pub buf_handle: i32,
     pub place_type: PlaceTypeRecord,
     pub frecency: Frecency,
     pub cursor_pos: CursorPosition,
+    pub pinned: bool,
+    pub note: Option<String>,
+    pub slot: Option<u8>,
+    pub captured_line: Option<String>,
+    pub stale_since: Option<Timestamp>,
 }
 
-#[derive(Decode, Encode, Default)]
+/// Bumped on every change of the layout, the previous ones are migrated on load
+#[derive(Decode, Encode, Default, PartialEq, Eq)]
 pub enum Version {
-    #[default]
     One = 1,
+    #[default]
+    Two = 2,
 }
 
 impl TryFrom<&Record> for PersistentRecord {
@@ -42,25 +70,64 @@ impl TryFrom<&Record> for PersistentRecord {
 
     fn try_from(
         Record {
+            id,
             buf,
             lazy_extmark,
+            place_type,
             frecency,
+            pinned,
+            note,
+            slot,
+            captured_line,
+            stale_since,
             ..
         }: &Record,
     ) -> Result<Self> {
         let cursor_pos = lazy_extmark.pos(buf.clone());
 
         Ok(Self {
+            id: *id,
             buf_handle: buf.handle(),
-            place_type: PlaceTypeRecord::Change(ChangeTypeRecord::Restored),
+            place_type: match place_type {
+                PlaceTypeRecord::Change(_) => PlaceTypeRecord::Change(ChangeTypeRecord::Restored),
+                PlaceTypeRecord::Bookmark { .. }
+                | PlaceTypeRecord::Jump
+                | PlaceTypeRecord::Quickfix => place_type.clone(),
+            },
             cursor_pos,
             // TODO: this is bad
             frecency: frecency.clone(),
+            pinned: *pinned,
+            note: note.clone(),
+            slot: *slot,
+            captured_line: captured_line.clone(),
+            stale_since: *stale_since,
         })
     }
 }
 
-impl TryFrom<&TrackList<Record>> for Session {
+impl TryFrom<&Tracker> for Session {
+    type Error = Error;
+
+    fn try_from(tracker: &Tracker) -> Result<Self> {
+        let mut workspaces = Vec::with_capacity(tracker.workspaces.len());
+        for w in tracker.workspaces.iter() {
+            workspaces.push(PersistentWorkspace {
+                name: w.name.clone(),
+                data: (&w.list).try_into()?,
+            });
+        }
+
+        Ok(Self {
+            version: Version::default(),
+            data: (&tracker.list).try_into()?,
+            name: tracker.list_name.clone(),
+            workspaces,
+        })
+    }
+}
+
+impl TryFrom<&TrackList<Record>> for DataSession {
     type Error = Error;
 
     fn try_from(data: &TrackList<Record>) -> Result<Self> {
@@ -69,53 +136,125 @@ impl TryFrom<&TrackList<Record>> for Session {
             records.push(r.try_into()?);
         }
 
+        let mut branches: Vec<PersistentBranch> = Vec::new();
+        flatten_branches(&data.branches, None, &mut branches)?;
+
         Ok(Self {
-            version: Version::default(),
-            data: DataSession {
-                pos: data.pos,
-                records,
-            },
+            pos: data.pos,
+            records,
+            branches,
         })
     }
 }
 
-impl TryFrom<Session> for TrackList<Record> {
+fn flatten_branches(
+    branches: &[Branch<Record>],
+    parent: Option<usize>,
+    flat: &mut Vec<PersistentBranch>,
+) -> Result<()> {
+    for b in branches {
+        let mut records: Vec<PersistentRecord> = Vec::with_capacity(b.ring.len());
+        for r in b.ring.iter() {
+            records.push(r.try_into()?);
+        }
+
+        flat.push(PersistentBranch {
+            parent,
+            fork: b.fork,
+            records,
+        });
+        flatten_branches(&b.branches, Some(flat.len() - 1), flat)?;
+    }
+
+    Ok(())
+}
+
+impl PersistentRecord {
+    /// Returns none if the record's buffer no longer exists
+    fn into_record(self, time: RecordMarkTime) -> Option<Record> {
+        let PersistentRecord {
+            id,
+            buf_handle,
+            place_type,
+            frecency,
+            cursor_pos,
+            pinned,
+            note,
+            slot,
+            captured_line,
+            stale_since,
+        } = self;
+
+        let buf: Buffer = buf_handle.into();
+        if !buf.is_valid() {
+            return None;
+        }
+
+        Some(Record {
+            id: id.reserve(),
+            buf,
+            win: None,
+            place_type,
+            lazy_extmark: LazyExtmark::Unloaded((cursor_pos, time)),
+            frecency,
+            pinned,
+            note,
+            slot,
+            captured_line,
+            stale_since,
+        })
+    }
+}
+
+impl TryFrom<DataSession> for TrackList<Record> {
     type Error = Error;
 
-    fn try_from(session: Session) -> Result<Self> {
+    fn try_from(data: DataSession) -> Result<Self> {
         let mut track_list: TrackList<Record> =
-            TrackList::with_capacity(session.data.records.len(), session.data.pos);
-
-        for r in session.data.records.into_iter().enumerate().filter_map(
-            |(
-                i,
-                PersistentRecord {
-                    buf_handle,
-                    place_type,
-                    frecency,
-                    cursor_pos,
-                },
-            )| {
-                let buf: Buffer = buf_handle.into();
-
-                if !buf.is_valid() {
-                    return None;
-                }
+            TrackList::with_capacity(data.records.len(), data.pos);
 
-                Some(Record {
-                    buf,
-                    place_type,
-                    lazy_extmark: LazyExtmark::Unloaded((
-                        cursor_pos,
-                        recreate_mark_time(i, session.data.pos),
-                    )),
-                    frecency,
-                })
-            },
-        ) {
+        for r in data
+            .records
+            .into_iter()
+            .enumerate()
+            .filter_map(|(i, r)| r.into_record(recreate_mark_time(i, data.pos)))
+        {
             track_list.push_plain(r);
         }
 
+        // Children always come after their parents, so attach them going backwards
+        let mut slots: Vec<Option<Branch<Record>>> = Vec::new();
+        let mut parents: Vec<Option<usize>> = Vec::new();
+        for PersistentBranch {
+            parent,
+            fork,
+            records,
+        } in data.branches
+        {
+            slots.push(Some(Branch {
+                fork,
+                ring: records
+                    .into_iter()
+                    .filter_map(|r| r.into_record(RecordMarkTime::Past))
+                    .collect(),
+                branches: Vec::new(),
+            }));
+            parents.push(parent);
+        }
+        for i in (0..slots.len()).rev() {
+            let Some(b) = slots.get_mut(i).and_then(Option::take) else {
+                continue;
+            };
+            match parents.get(i).copied().flatten() {
+                Some(p) => {
+                    if let Some(Some(parent)) = slots.get_mut(p) {
+                        parent.branches.insert(0, b);
+                    }
+                }
+                None => track_list.branches.insert(0, b),
+            }
+        }
+
         Ok(track_list)
     }
 }
@@ -0,0 +1,131 @@
+//! Layouts of the previous session versions and their conversion into the current one
+
+use crate::{
+    common_types::CursorPosition,
+    state::{frecency::Frecency, ChangeTypeRecord, PlaceTypeRecord, RecordId},
+};
+
+use super::{DataSession, PersistentRecord, Session, Version};
+
+use bitcode::{Decode, Encode};
+
+#[derive(Decode, Encode)]
+pub struct SessionV1 {
+    pub version: VersionV1,
+    pub data: DataSessionV1,
+}
+
+#[derive(Decode, Encode)]
+pub enum VersionV1 {
+    One = 1,
+}
+
+#[derive(Decode, Encode)]
+pub struct DataSessionV1 {
+    pub pos: Option<usize>,
+    pub records: Vec<PersistentRecordV1>,
+}
+
+#[derive(Decode, Encode)]
+pub struct PersistentRecordV1 {
+    pub buf_handle: i32,
+    pub place_type: PlaceTypeRecordV1,
+    pub frecency: Frecency,
+    pub cursor_pos: CursorPosition,
+}
+
+#[derive(Decode, Encode)]
+pub enum PlaceTypeRecordV1 {
+    Change(ChangeTypeRecord),
+}
+
+impl From<PersistentRecordV1> for PersistentRecord {
+    fn from(
+        PersistentRecordV1 {
+            buf_handle,
+            place_type: PlaceTypeRecordV1::Change(c),
+            frecency,
+            cursor_pos,
+        }: PersistentRecordV1,
+    ) -> Self {
+        Self {
+            id: RecordId::next(),
+            buf_handle,
+            place_type: PlaceTypeRecord::Change(c),
+            frecency,
+            cursor_pos,
+            pinned: false,
+            note: None,
+            slot: None,
+            captured_line: None,
+            stale_since: None,
+        }
+    }
+}
+
+impl From<SessionV1> for Session {
+    fn from(SessionV1 { data, .. }: SessionV1) -> Self {
+        Self {
+            version: Version::default(),
+            data: DataSession {
+                pos: data.pos,
+                records: data.records.into_iter().map(Into::into).collect(),
+                branches: Vec::new(),
+            },
+            name: String::new(),
+            workspaces: Vec::new(),
+        }
+    }
+}
+
+mod tests {
+    use super::*;
+    use crate::state::session::decode_session;
+
+    #[test]
+    fn can_migrate_first_version() {
+        let old = SessionV1 {
+            version: VersionV1::One,
+            data: DataSessionV1 {
+                pos: Some(1),
+                records: Vec::from([
+                    PersistentRecordV1 {
+                        buf_handle: 1,
+                        place_type: PlaceTypeRecordV1::Change(ChangeTypeRecord::Restored),
+                        frecency: Frecency::new(),
+                        cursor_pos: (3, 0).into(),
+                    },
+                    PersistentRecordV1 {
+                        buf_handle: 2,
+                        place_type: PlaceTypeRecordV1::Change(ChangeTypeRecord::Restored),
+                        frecency: Frecency::new(),
+                        cursor_pos: (7, 2).into(),
+                    },
+                ]),
+            },
+        };
+
+        let got = decode_session(&bitcode::encode(&old)).unwrap();
+
+        assert!(got.version == Version::Two);
+        assert_eq!(got.data.pos, Some(1));
+        assert_eq!(
+            got.data
+                .records
+                .iter()
+                .map(|r| (r.buf_handle, r.cursor_pos.clone()))
+                .collect::<Vec<_>>(),
+            Vec::from([(1, (3, 0).into()), (2, (7, 2).into())])
+        );
+        assert!(got
+            .data
+            .records
+            .iter()
+            .all(|r| !r.pinned && r.slot.is_none()));
+    }
+
+    #[test]
+    fn fails_on_unknown_layout() {
+        assert!(decode_session(&[0xde, 0xad, 0xbe, 0xef]).is_err());
+    }
+}
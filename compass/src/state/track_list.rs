@@ -1,16 +1,38 @@
 use std::{
-    collections::vec_deque::{Iter, IterMut, VecDeque},
+    collections::{
+        vec_deque::{Iter, IterMut, VecDeque},
+        HashMap,
+    },
     iter::Rev,
 };
 
 use nvim_oxi::api::Window;
 
-use crate::{state::frecency::FrecencyScore, Result};
+use crate::{
+    state::{frecency::FrecencyScore, RecordId},
+    Result,
+};
 
 #[derive(Clone, Debug, PartialEq, Eq)]
 pub struct TrackList<T> {
     ring: VecDeque<T>,
     pub pos: Option<usize>,
+    /// Alternative lines of records split off the current one
+    pub branches: Vec<Branch<T>>,
+    /// Positions of the other windows or tabs sharing the list, keyed by their handles,
+    /// held as the record at the position so that they survive reordering of the ring
+    pub parked: HashMap<i32, Option<RecordId>>,
+}
+
+/// Records that were in the future when a new one got pushed with branching enabled
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Branch<T> {
+    /// Newest record this branch shares with the line it was split off, none if it shares none
+    pub fork: Option<RecordId>,
+    /// Ordered the same way as the main ring, from the newest to the oldest
+    pub ring: VecDeque<T>,
+    /// Branches split off the records of this one
+    pub branches: Vec<Branch<T>>,
 }
 
 impl<T> Default for TrackList<T> {
@@ -18,6 +40,8 @@ impl<T> Default for TrackList<T> {
         Self {
             ring: VecDeque::default(),
             pos: None,
+            branches: Vec::default(),
+            parked: HashMap::default(),
         }
     }
 }
@@ -30,11 +54,17 @@ pub trait Active {
     fn is_active(&self) -> bool;
 }
 
+pub trait Identify {
+    fn id(&self) -> RecordId;
+}
+
 impl<T> TrackList<T> {
     pub fn with_capacity(capacity: usize, pos: Option<usize>) -> Self {
         Self {
             ring: VecDeque::with_capacity(capacity),
             pos,
+            branches: Vec::default(),
+            parked: HashMap::default(),
         }
     }
 
@@ -74,14 +104,153 @@ impl<T> TrackList<T> {
 
 impl<T> TrackList<T>
 where
-    T: IndicateCloseness + Mark + Active,
+    T: Identify,
+{
+    fn idx_of(&self, id: RecordId) -> Option<usize> {
+        self.ring.iter().position(|v| v.id() == id)
+    }
+
+    /// Keeps the forks of branches and the parked positions on existing records
+    /// before the element at the index is removed, moving them to its older and newer neighbour
+    fn forget(&mut self, i: usize) {
+        let Some(id) = self.ring.get(i).map(Identify::id) else {
+            return;
+        };
+
+        let older = self.ring.get(i + 1).map(Identify::id);
+        for b in self.branches.iter_mut().filter(|b| b.fork == Some(id)) {
+            b.fork = older;
+        }
+
+        let newer = i
+            .checked_sub(1)
+            .and_then(|n| self.ring.get(n))
+            .map(Identify::id);
+        for parked in self.parked.values_mut().filter(|p| **p == Some(id)) {
+            *parked = newer;
+        }
+    }
+
+    /// Points the positions parked on the records that are no longer in the line at the fork
+    fn repark(&mut self, fork: Option<RecordId>) {
+        let stashed: Vec<RecordId> = self
+            .parked
+            .values()
+            .flatten()
+            .filter(|&&id| self.idx_of(id).is_none())
+            .copied()
+            .collect();
+        for parked in self
+            .parked
+            .values_mut()
+            .filter(|p| p.is_some_and(|id| stashed.contains(&id)))
+        {
+            *parked = fork;
+        }
+    }
+
+    /// Stashes the records in the future as a separate branch,
+    /// so that new ones do not get interleaved with them
+    pub fn fork(&mut self) {
+        let Some(p) = self.pos else {
+            return;
+        };
+
+        let fork = self.ring.get(p + 1).map(Identify::id);
+        let ring: VecDeque<T> = self.ring.drain(..=p).collect();
+        self.repark(fork);
+        let (nested, kept): (Vec<Branch<T>>, Vec<Branch<T>>) = std::mem::take(&mut self.branches)
+            .into_iter()
+            .partition(|b| b.fork.is_some_and(|f| ring.iter().any(|v| v.id() == f)));
+
+        self.branches = kept;
+        self.branches.push(Branch {
+            fork,
+            ring,
+            branches: nested,
+        });
+        self.pos = None;
+    }
+
+    /// Number of the records newer than the fork of the branch,
+    /// all of them if the fork is no longer in the list
+    pub fn len_past_fork(&self, idx: usize) -> Option<usize> {
+        let fork = self.branches.get(idx)?.fork;
+        Some(fork.and_then(|f| self.idx_of(f)).unwrap_or(self.ring.len()))
+    }
+
+    /// Records stashed in the branches, the nested ones included
+    pub fn iter_branches(&self) -> impl Iterator<Item = &T> {
+        fn collect<'a, T>(branches: &'a [Branch<T>], records: &mut Vec<&'a T>) {
+            for b in branches {
+                records.extend(b.ring.iter());
+                collect(&b.branches, records);
+            }
+        }
+
+        let mut records = Vec::new();
+        collect(&self.branches, &mut records);
+        records.into_iter()
+    }
+
+    /// Removes the records stashed in the branches that `keep` rejects, it is also given
+    /// the older records of the same branch. Forks on the removed records move to the next older one
+    /// and the emptied branches are dropped, the ones split off them taking their place
+    pub fn retain_branches(&mut self, mut keep: impl FnMut(&T, Iter<T>) -> bool) {
+        fn retain<T: Identify>(
+            branches: &mut Vec<Branch<T>>,
+            keep: &mut impl FnMut(&T, Iter<T>) -> bool,
+        ) {
+            let mut i = 0;
+            while let Some(b) = branches.get_mut(i) {
+                retain(&mut b.branches, keep);
+
+                let mut j = 0;
+                while let Some(v) = b.ring.get(j) {
+                    if keep(v, b.ring.range(j + 1..)) {
+                        j += 1;
+                        continue;
+                    }
+
+                    let id = v.id();
+                    b.ring.remove(j);
+                    let older = b.ring.get(j).map(Identify::id).or(b.fork);
+                    for nested in b.branches.iter_mut().filter(|n| n.fork == Some(id)) {
+                        nested.fork = older;
+                    }
+                }
+
+                if b.ring.is_empty() {
+                    let nested = std::mem::take(&mut b.branches);
+                    let len = nested.len();
+                    branches.splice(i..=i, nested);
+                    i += len;
+                } else {
+                    i += 1;
+                }
+            }
+        }
+
+        retain(&mut self.branches, &mut keep);
+    }
+}
+
+impl<T> TrackList<T>
+where
+    T: IndicateCloseness + Mark + Active + Identify,
 {
-    fn active_close_past_idx(&mut self) -> Option<usize> {
+    /// The filter is given a candidate and the record the move started from if it is known
+    fn active_close_past_idx(
+        &self,
+        filter: &impl Fn(&T, Option<&T>) -> bool,
+        from: Option<usize>,
+    ) -> Option<usize> {
+        let from = from.and_then(|f| self.ring.get(f));
         self.ring
             .iter()
             .enumerate()
             .skip(self.pos.map(|p| p + 1).unwrap_or(0))
-            .find_map(|(i, v)| match v.is_active() {
+            .find_map(|(i, v)| match v.is_active() && filter(v, from) {
                 true => Some(i),
                 false => None,
             })
@@ -113,98 +282,244 @@ where
         }
     }
 
-    pub fn step_past(&mut self, win: Window) -> Option<&mut T> {
-        let idx = self.active_close_past_idx()?;
+    /// Moves the position to the closest active record in the past passing the filter
+    /// without jumping, the skipped records become the future
+    fn shift_past(
+        &mut self,
+        filter: &impl Fn(&T, Option<&T>) -> bool,
+        from: Option<usize>,
+    ) -> Option<usize> {
+        let old_close_fut = self.pos.unwrap_or(0);
+        let idx = self.active_close_past_idx(filter, from)?;
         self.pos = Some(idx);
 
-        if let Some(cf) = self.ring.get_mut(idx) {
-            cf.jump(win).ok()?;
-            cf.as_close_future();
-        } else {
-            // Should not be reachable
-            return None;
+        for fut in self.ring.range_mut(old_close_fut..idx) {
+            fut.as_future();
         }
-
+        self.ring.get_mut(idx)?.as_close_future();
         if let Some(cp) = self.ring.get_mut(idx + 1) {
             cp.as_close_past();
         }
         if let Some(p) = self.ring.get_mut(idx + 2) {
             p.as_past();
         }
-        if let Some(fut) = idx.checked_sub(1).and_then(|i| self.ring.get_mut(i)) {
-            fut.as_future();
-        }
 
-        self.ring.get_mut(idx)
+        Some(idx)
     }
 
-    pub fn step_future(&mut self, win: Window) -> Option<&mut T> {
+    /// Moves the position to the closest record in the future passing the filter
+    /// without jumping, the skipped records become the past
+    fn shift_future(
+        &mut self,
+        filter: &impl Fn(&T, Option<&T>) -> bool,
+        from: Option<usize>,
+    ) -> Option<usize> {
         let pos = self.pos?;
-
-        {
-            let curr = self.ring.get_mut(pos)?;
-            curr.jump(win).ok()?;
-            curr.as_close_past();
-        }
+        let from = from.and_then(|f| self.ring.get(f));
+        let idx = (0..=pos)
+            .rev()
+            .find(|&i| self.ring.get(i).is_some_and(|v| filter(v, from)))?;
 
         if let Some(past) = self.ring.get_mut(pos + 1) {
             past.as_past();
         };
+        for past in self.ring.range_mut(idx + 1..=pos) {
+            past.as_past();
+        }
+        self.ring.get_mut(idx)?.as_close_past();
 
-        self.pos = pos.checked_sub(1);
+        self.pos = idx.checked_sub(1);
         if let Some(i) = self.pos {
             if let Some(close_fut) = self.ring.get_mut(i) {
                 close_fut.as_close_future();
             };
         };
 
-        self.ring.get_mut(pos)
+        Some(idx)
     }
 
-    pub fn pop_past(&mut self, win: Window) -> Option<T> {
-        let idx = self.active_close_past_idx()?;
-        let skipped = self.pos.map(|p| p + 1).unwrap_or(0).abs_diff(idx);
-        if let Some(cp) = self.ring.get_mut(idx) {
-            cp.jump(win).ok()?;
+    /// Puts the position at the opposite end of the list
+    fn wrap_around(&mut self, to_newest: bool) {
+        self.pos = match to_newest {
+            true => None,
+            false => self.ring.len().checked_sub(1),
+        };
+        self.refresh_closeness();
+    }
+
+    /// Jumps to the record at the index and makes it the close future,
+    /// the position stays where it was if the jump fails
+    pub fn step_to(&mut self, win: Window, idx: usize) -> Result<Option<&mut T>> {
+        let Some(cf) = self.ring.get_mut(idx) else {
+            return Ok(None);
+        };
+        cf.jump(win)?;
+
+        self.pos = Some(idx);
+        self.refresh_closeness();
+
+        Ok(self.ring.get_mut(idx))
+    }
+
+    /// Moves up to `count` active records passing the filter into the past,
+    /// jumping only to the last one. The filter is also given the previously
+    /// stepped over record, `None` for the first step
+    pub fn step_past(
+        &mut self,
+        win: Window,
+        count: usize,
+        wrap: bool,
+        filter: impl Fn(&T, Option<&T>) -> bool,
+    ) -> Option<&mut T> {
+        let mut idx = None;
+        for _ in 0..count {
+            let shifted = match self.shift_past(&filter, idx) {
+                None if wrap => {
+                    let pos = self.pos;
+                    self.wrap_around(true);
+                    let shifted = self.shift_past(&filter, idx);
+                    if shifted.is_none() {
+                        self.pos = pos;
+                        self.refresh_closeness();
+                    }
+                    shifted
+                }
+                shifted => shifted,
+            };
+            match shifted {
+                Some(i) => idx = Some(i),
+                None => break,
+            }
+        }
+
+        let cf = self.ring.get_mut(idx?)?;
+        cf.jump(win).ok()?;
+
+        Some(cf)
+    }
+
+    /// Moves up to `count` records passing the filter into the future,
+    /// jumping only to the last one. The filter is also given the previously
+    /// stepped over record, `None` for the first step
+    pub fn step_future(
+        &mut self,
+        win: Window,
+        count: usize,
+        wrap: bool,
+        filter: impl Fn(&T, Option<&T>) -> bool,
+    ) -> Option<&mut T> {
+        let mut idx = None;
+        for _ in 0..count {
+            let shifted = match self.shift_future(&filter, idx) {
+                None if wrap => {
+                    let pos = self.pos;
+                    self.wrap_around(false);
+                    let shifted = self.shift_future(&filter, idx);
+                    if shifted.is_none() {
+                        self.pos = pos;
+                        self.refresh_closeness();
+                    }
+                    shifted
+                }
+                shifted => shifted,
+            };
+            match shifted {
+                Some(i) => idx = Some(i),
+                None => break,
+            }
         }
-        if let Some(p) = self.pos {
-            self.pos = Some(p + skipped);
-        } else {
-            self.pos = skipped.checked_sub(1);
+
+        let cp = self.ring.get_mut(idx?)?;
+        cp.jump(win).ok()?;
+
+        Some(cp)
+    }
+
+    /// Pops the `count`-th active record passing the filter in the past,
+    /// or the oldest one if there are fewer, together with the index it was at
+    pub fn pop_past(
+        &mut self,
+        win: Window,
+        count: usize,
+        filter: impl Fn(&T) -> bool,
+    ) -> Option<(usize, T)> {
+        let available = self
+            .ring
+            .iter()
+            .skip(self.pos.map(|p| p + 1).unwrap_or(0))
+            .filter(|v| v.is_active() && filter(v))
+            .count();
+        if available == 0 || count == 0 {
+            return None;
+        }
+        let filter = |v: &T, _: Option<&T>| filter(v);
+        for _ in 1..count.min(available) {
+            self.shift_past(&filter, None);
         }
 
+        let idx = self.active_close_past_idx(&filter, None)?;
+        self.ring.get_mut(idx)?.jump(win).ok()?;
+        self.shift_past(&filter, None);
+
+        self.pos = idx.checked_sub(1);
+        self.forget(idx);
         let popped = self.ring.remove(idx)?;
 
-        if let Some(cp) = self.ring.get_mut(idx) {
-            cp.as_close_past();
-        }
-        if skipped != 0 {
-            if let Some(cf) = idx.checked_sub(1).and_then(|i| self.ring.get_mut(i)) {
-                cf.as_close_future();
-            }
-            if let Some(fut) = idx.checked_sub(2).and_then(|i| self.ring.get_mut(i)) {
-                fut.as_future();
-            }
+        if let Some(cf) = self.pos.and_then(|i| self.ring.get_mut(i)) {
+            cf.as_close_future();
         }
 
-        Some(popped)
+        Some((idx, popped))
     }
 
-    pub fn pop_future(&mut self, win: Window) -> Option<T> {
+    /// Pops the `count`-th record passing the filter in the future,
+    /// or the newest one if there are fewer, together with the index it was at
+    pub fn pop_future(
+        &mut self,
+        win: Window,
+        count: usize,
+        filter: impl Fn(&T) -> bool,
+    ) -> Option<(usize, T)> {
+        let available = self
+            .ring
+            .iter()
+            .take(self.pos? + 1)
+            .filter(|v| filter(v))
+            .count();
+        if available == 0 || count == 0 {
+            return None;
+        }
+        for _ in 1..count.min(available) {
+            self.shift_future(&|v, _| filter(v), None);
+        }
+
         let pos = self.pos?;
-        let new_pos = pos.checked_sub(1);
+        let idx = (0..=pos)
+            .rev()
+            .find(|&i| self.ring.get(i).is_some_and(&filter))?;
+        self.ring.get_mut(idx)?.jump(win).ok()?;
+        self.shift_future(&|v, _| filter(v), None);
 
-        if let Some(cf) = self.get_mut(pos) {
-            cf.jump(win).ok()?;
-        }
+        self.forget(idx);
+        let popped = self.ring.remove(idx)?;
 
-        if let Some(cf) = new_pos.and_then(|i| self.ring.get_mut(i)) {
-            cf.as_close_future();
+        if let Some(cp) = self.ring.get_mut(idx) {
+            cp.as_close_past();
         }
 
-        self.pos = new_pos;
+        Some((idx, popped))
+    }
 
-        self.ring.remove(pos)
+    /// Puts the value back at the index it was removed from, keeping the current position on
+    /// the same records, or at the oldest end if the list got shorter since
+    pub fn insert(&mut self, i: usize, val: T) {
+        let i = i.min(self.ring.len());
+        if let Some(p) = self.pos.filter(|&p| i <= p) {
+            self.pos = Some(p + 1);
+        }
+
+        self.ring.insert(i, val);
+        self.refresh_closeness();
     }
 
     pub fn remove(&mut self, i: usize) -> Option<T> {
@@ -242,9 +557,93 @@ where
             _ => {}
         }
 
+        self.forget(i);
         self.ring.remove(i)
     }
 
+    /// Moves the value under one index to another, the position stays on the same records
+    pub fn reorder(&mut self, from: usize, to: usize) -> Option<()> {
+        if from >= self.ring.len() || to >= self.ring.len() {
+            return None;
+        }
+
+        let follow = |i: usize| match i {
+            _ if i == from => to,
+            _ => {
+                let i = i - usize::from(i > from);
+                i + usize::from(i >= to)
+            }
+        };
+        self.pos = self.pos.map(follow);
+
+        let val = self.ring.remove(from)?;
+        self.ring.insert(to, val);
+
+        self.refresh_closeness();
+        Some(())
+    }
+
+    /// Reapplies closeness to every element according to the current position
+    pub fn refresh_closeness(&mut self) {
+        let close_past = self.pos.map(|p| p + 1).unwrap_or(0);
+        for (i, v) in self.ring.iter_mut().enumerate() {
+            match i {
+                _ if i == close_past => v.as_close_past(),
+                _ if i > close_past => v.as_past(),
+                _ if i + 1 == close_past => v.as_close_future(),
+                _ => v.as_future(),
+            }
+        }
+    }
+
+    /// Parks the current position under the key and restores the one parked under the other,
+    /// a key seen for the first time starts from the current position
+    pub fn switch_cursor(&mut self, from: i32, to: i32) {
+        let restored = self.parked.remove(&to);
+        let parked = self.pos.and_then(|p| self.ring.get(p)).map(Identify::id);
+        self.parked.insert(from, parked);
+
+        if let Some(restored) = restored {
+            self.pos = restored.and_then(|id| self.idx_of(id));
+            self.refresh_closeness();
+        }
+    }
+
+    /// Swaps the records newer than the fork of the chosen branch with the ones of that branch,
+    /// leaving us at the fork with the branch in the future. Afterwards come the branches
+    /// that were left in place, then the line we switched from, then the ones split off the new line
+    pub fn switch_branch(&mut self, idx: usize) -> Option<()> {
+        let len_top = self.len_past_fork(idx)?;
+        let Branch { ring, branches, .. } = self.branches.remove(idx);
+
+        let top: VecDeque<T> = self.ring.drain(..len_top).collect();
+        let fork = self.ring.front().map(Identify::id);
+        let (nested, kept): (Vec<Branch<T>>, Vec<Branch<T>>) = std::mem::take(&mut self.branches)
+            .into_iter()
+            .partition(|b| b.fork.is_some_and(|f| top.iter().any(|v| v.id() == f)));
+
+        self.branches = kept;
+        match top.is_empty() {
+            true => self.branches.extend(nested),
+            false => self.branches.push(Branch {
+                fork,
+                ring: top,
+                branches: nested,
+            }),
+        }
+        self.branches.extend(branches);
+
+        self.pos = ring.len().checked_sub(1);
+        let mut ring = ring;
+        ring.append(&mut self.ring);
+        self.ring = ring;
+        self.repark(fork);
+
+        self.refresh_closeness();
+
+        Some(())
+    }
+
     pub fn make_close_past(&mut self, idx: usize) -> Option<()> {
         if self.pos.map(|p| p + 1).unwrap_or(0) == idx {
             return Some(());
@@ -410,6 +809,7 @@ mod tests {
         as_close_past: bool,
         as_future: bool,
         as_close_future: bool,
+        unreachable: bool,
     }
     impl Stub {
         fn new(id: i32, active: bool) -> Self {
@@ -420,6 +820,7 @@ mod tests {
                 as_close_past: false,
                 as_future: false,
                 as_close_future: false,
+                unreachable: false,
             }
         }
     }
@@ -454,6 +855,9 @@ mod tests {
     }
     impl Mark for Stub {
         fn jump(&mut self, _: Window) -> Result<()> {
+            if self.unreachable {
+                Err(crate::InputError::Other("unreachable stub".to_owned()))?
+            }
             Ok(())
         }
     }
@@ -462,6 +866,11 @@ mod tests {
             self.active
         }
     }
+    impl Identify for Stub {
+        fn id(&self) -> RecordId {
+            RecordId(self.id as u64)
+        }
+    }
 
     #[nvim_oxi::test]
     fn can_go_to_oldest() {
@@ -476,46 +885,334 @@ mod tests {
         assert!(list.pos.is_none());
 
         let win = get_current_win();
-        assert_eq!(list.step_past(win.clone()).unwrap(), &4.into());
-        assert_eq!(list.step_past(win.clone()).unwrap(), &3.into());
+        assert_eq!(
+            list.step_past(win.clone(), 1, false, |_, _| true).unwrap(),
+            &4.into()
+        );
+        assert_eq!(
+            list.step_past(win.clone(), 1, false, |_, _| true).unwrap(),
+            &3.into()
+        );
+
+        assert_eq!(
+            list.step_future(win.clone(), 1, false, |_, _| true)
+                .unwrap(),
+            &3.into()
+        );
+
+        assert_eq!(
+            list.step_past(win.clone(), 1, false, |_, _| true).unwrap(),
+            &3.into()
+        );
+        assert_eq!(
+            list.step_past(win.clone(), 1, false, |_, _| true).unwrap(),
+            &2.into()
+        );
+        assert_eq!(
+            list.step_past(win.clone(), 1, false, |_, _| true).unwrap(),
+            &1.into()
+        );
+    }
+
+    #[nvim_oxi::test]
+    fn prevents_out_of_bounds() {
+        let mut list = TrackList::<Stub>::default();
+        list.push(1.into());
+        list.push(2.into());
+        list.push(3.into());
+
+        let win = get_current_win();
+        assert!(list
+            .step_future(win.clone(), 1, false, |_, _| true)
+            .is_none());
+        assert!(list
+            .step_future(win.clone(), 1, false, |_, _| true)
+            .is_none());
+
+        assert!(list.pos.is_none());
+        assert!(list
+            .step_future(win.clone(), 1, false, |_, _| true)
+            .is_none());
+        assert!(list
+            .step_future(win.clone(), 1, false, |_, _| true)
+            .is_none());
+
+        assert_eq!(
+            list.step_past(win.clone(), 1, false, |_, _| true).unwrap(),
+            &3.into()
+        );
+
+        list.push(22.into());
+
+        assert_eq!(
+            list.step_past(win.clone(), 1, false, |_, _| true).unwrap(),
+            &22.into()
+        );
+        assert_eq!(
+            list.step_past(win.clone(), 1, false, |_, _| true).unwrap(),
+            &2.into()
+        );
+        assert_eq!(
+            list.step_past(win.clone(), 1, false, |_, _| true).unwrap(),
+            &1.into()
+        );
+
+        assert!(list.step_past(win.clone(), 1, false, |_, _| true).is_none());
+        assert_eq!(list.get_mut(list.pos.unwrap()).unwrap(), &1.into());
+        assert!(list.step_past(win.clone(), 1, false, |_, _| true).is_none());
+        assert_eq!(
+            list.step_future(win.clone(), 1, false, |_, _| true)
+                .unwrap(),
+            &1.into()
+        );
+        assert_eq!(
+            list.step_future(win.clone(), 1, false, |_, _| true)
+                .unwrap(),
+            &2.into()
+        );
+        assert_eq!(
+            list.step_future(win.clone(), 1, false, |_, _| true)
+                .unwrap(),
+            &22.into()
+        );
+        assert_eq!(
+            list.step_future(win.clone(), 1, false, |_, _| true)
+                .unwrap(),
+            &3.into()
+        );
+    }
+
+    #[nvim_oxi::test]
+    fn can_step_by_count() {
+        let mut list = TrackList::<Stub>::default();
+        list.push(1.into());
+        list.push(2.into());
+        list.push(3.into());
+        list.push(4.into());
+
+        let win = get_current_win();
+        assert_eq!(
+            list.step_past(win.clone(), 3, false, |_, _| true).unwrap(),
+            &2.into()
+        );
+        assert_eq!(
+            list.step_future(win.clone(), 2, false, |_, _| true)
+                .unwrap(),
+            &3.into()
+        );
+        assert_eq!(
+            list.step_past(win.clone(), 10, false, |_, _| true).unwrap(),
+            &1.into()
+        );
+        assert!(list.step_past(win.clone(), 2, false, |_, _| true).is_none());
+        assert_eq!(
+            list.step_future(win.clone(), 10, false, |_, _| true)
+                .unwrap(),
+            &4.into()
+        );
+        assert!(list.pos.is_none());
+    }
 
-        assert_eq!(list.step_future(win.clone()).unwrap(), &3.into());
+    #[nvim_oxi::test]
+    fn can_pop_by_count() {
+        let mut list = TrackList::<Stub>::default();
+        list.push(1.into());
+        list.push(2.into());
+        list.push(3.into());
+        list.push(4.into());
 
-        assert_eq!(list.step_past(win.clone()).unwrap(), &3.into());
-        assert_eq!(list.step_past(win.clone()).unwrap(), &2.into());
-        assert_eq!(list.step_past(win.clone()).unwrap(), &1.into());
+        let win = get_current_win();
+        assert_eq!(list.pop_past(win.clone(), 2, |_| true).unwrap().1, 3.into());
+        assert_eq!(
+            list.step_past(win.clone(), 1, false, |_, _| true).unwrap(),
+            &2.into()
+        );
+        assert_eq!(
+            list.pop_future(win.clone(), 10, |_| true).unwrap().1,
+            4.into()
+        );
+        assert_eq!(
+            list.pop_past(win.clone(), 10, |_| true).unwrap().1,
+            1.into()
+        );
+        assert_eq!(list.len(), 1);
     }
 
     #[nvim_oxi::test]
-    fn prevents_out_of_bounds() {
+    fn can_insert_popped_back_in_place() {
+        let mut list = TrackList::<Stub>::default();
+        list.push(1.into());
+        list.push(2.into());
+        list.push(3.into());
+        list.push(4.into());
+
+        let win = get_current_win();
+        let (idx, popped) = list.pop_past(win.clone(), 2, |_| true).unwrap();
+        assert_eq!(popped, 3.into());
+        let pos = list.pos;
+
+        list.insert(idx, popped);
+        assert_eq!(list.get(idx).unwrap(), &3.into());
+        assert_eq!(list.len(), 4);
+        assert_eq!(list.pos, pos);
+    }
+
+    #[nvim_oxi::test]
+    fn can_reorder_keeping_position() {
+        let mut list = TrackList::<Stub>::default();
+        list.push(1.into());
+        list.push(2.into());
+        list.push(3.into());
+        list.push(4.into());
+
+        let win = get_current_win();
+        list.step_past(win.clone(), 2, false, |_, _| true);
+        let close_future = list.get(list.pos.unwrap()).copied().unwrap();
+
+        list.reorder(3, 0).unwrap();
+        assert_eq!(list.get(0).unwrap(), &1.into());
+        assert_eq!(list.get(list.pos.unwrap()).unwrap(), &close_future);
+        assert!(list.reorder(0, 4).is_none());
+    }
+
+    #[nvim_oxi::test]
+    fn can_step_through_filtered_records() {
         let mut list = TrackList::<Stub>::default();
         list.push(1.into());
         list.push(2.into());
         list.push(3.into());
+        list.push(4.into());
+        list.push(5.into());
 
         let win = get_current_win();
-        assert!(list.step_future(win.clone()).is_none());
-        assert!(list.step_future(win.clone()).is_none());
+        let odd = |s: &Stub, _: Option<&Stub>| s.id % 2 == 1;
+        assert_eq!(
+            list.step_past(win.clone(), 1, false, odd).unwrap(),
+            &5.into()
+        );
+        assert_eq!(
+            list.step_past(win.clone(), 1, false, odd).unwrap(),
+            &3.into()
+        );
+        assert_eq!(list.pos, Some(2));
+        assert_eq!(
+            list.step_past(win.clone(), 1, false, |_, _| true).unwrap(),
+            &2.into()
+        );
+        assert_eq!(
+            list.step_future(win.clone(), 1, false, odd).unwrap(),
+            &3.into()
+        );
+        assert_eq!(
+            list.step_future(win.clone(), 1, false, odd).unwrap(),
+            &5.into()
+        );
+        assert!(list.pos.is_none());
+
+        assert_eq!(
+            list.pop_past(win.clone(), 2, |s| odd(s, None)).unwrap().1,
+            3.into()
+        );
+        assert_eq!(list.pos, Some(1));
+        assert_eq!(
+            list.step_past(win.clone(), 1, false, |_, _| true).unwrap(),
+            &2.into()
+        );
+    }
+
+    #[nvim_oxi::test]
+    fn keeps_position_when_step_to_fails() {
+        let mut list = TrackList::<Stub>::default();
+        list.push(1.into());
+        list.push(Stub {
+            unreachable: true,
+            ..2.into()
+        });
+        list.push(3.into());
 
+        let win = get_current_win();
+        assert!(list.step_to(win.clone(), 1).is_err());
         assert!(list.pos.is_none());
-        assert!(list.step_future(win.clone()).is_none());
-        assert!(list.step_future(win.clone()).is_none());
 
-        assert_eq!(list.step_past(win.clone()).unwrap(), &3.into());
+        assert_eq!(list.step_to(win.clone(), 2).unwrap().unwrap(), &1.into());
+        assert_eq!(list.pos, Some(2));
+    }
+
+    #[nvim_oxi::test]
+    fn can_wrap_around_the_ends() {
+        let mut list = TrackList::<Stub>::default();
+        list.push(1.into());
+        list.push(2.into());
+        list.push(3.into());
+
+        let win = get_current_win();
+        assert_eq!(
+            list.step_future(win.clone(), 1, true, |_, _| true).unwrap(),
+            &1.into()
+        );
+        assert_eq!(
+            list.step_past(win.clone(), 1, false, |_, _| true).unwrap(),
+            &1.into()
+        );
+        assert!(list.step_past(win.clone(), 1, false, |_, _| true).is_none());
+        assert_eq!(
+            list.step_past(win.clone(), 1, true, |_, _| true).unwrap(),
+            &3.into()
+        );
+        assert_eq!(list.pos, Some(0));
+    }
 
+    #[nvim_oxi::test]
+    fn can_step_over_records_like_the_previous_one() {
+        let mut list = TrackList::<Stub>::default();
+        list.push(1.into());
+        list.push(11.into());
+        list.push(2.into());
         list.push(22.into());
+        list.push(3.into());
 
-        assert_eq!(list.step_past(win.clone()).unwrap(), &22.into());
-        assert_eq!(list.step_past(win.clone()).unwrap(), &2.into());
-        assert_eq!(list.step_past(win.clone()).unwrap(), &1.into());
+        let win = get_current_win();
+        let other_ten =
+            |s: &Stub, from: Option<&Stub>| s.id % 10 != from.map(|f| f.id % 10).unwrap_or(3);
+        assert_eq!(
+            list.step_past(win.clone(), 1, false, other_ten).unwrap(),
+            &22.into()
+        );
+        assert_eq!(
+            list.step_past(win.clone(), 1, false, |_, _| true).unwrap(),
+            &2.into()
+        );
+        list.step_future(win.clone(), 2, false, |_, _| true);
+        assert_eq!(
+            list.step_past(win.clone(), 2, false, other_ten).unwrap(),
+            &11.into()
+        );
+    }
 
-        assert!(list.step_past(win.clone()).is_none());
-        assert_eq!(list.get_mut(list.pos.unwrap()).unwrap(), &1.into());
-        assert!(list.step_past(win.clone()).is_none());
-        assert_eq!(list.step_future(win.clone()).unwrap(), &1.into());
-        assert_eq!(list.step_future(win.clone()).unwrap(), &2.into());
-        assert_eq!(list.step_future(win.clone()).unwrap(), &22.into());
-        assert_eq!(list.step_future(win.clone()).unwrap(), &3.into());
+    #[nvim_oxi::test]
+    fn keeps_parked_positions_on_the_same_records() {
+        let mut list = TrackList::<Stub>::default();
+        list.push(1.into());
+        list.push(2.into());
+        list.push(3.into());
+
+        let win = get_current_win();
+        list.step_past(win.clone(), 2, false, |_, _| true);
+        assert_eq!(list.pos, Some(1));
+
+        list.switch_cursor(1, 2);
+        assert_eq!(list.pos, Some(1));
+        list.switch_cursor(2, 3);
+        list.step_future(win.clone(), 2, false, |_, _| true);
+        assert!(list.pos.is_none());
+        list.push(4.into());
+
+        list.switch_cursor(3, 1);
+        assert_eq!(list.pos, Some(2));
+        assert_eq!(
+            list.step_past(win.clone(), 1, false, |_, _| true).unwrap(),
+            &1.into()
+        );
     }
 
     #[nvim_oxi::test]
@@ -526,9 +1223,15 @@ mod tests {
         list.push(3.into());
 
         let win = get_current_win();
-        assert_eq!(list.step_past(win.clone()).unwrap(), &3.into());
+        assert_eq!(
+            list.step_past(win.clone(), 1, false, |_, _| true).unwrap(),
+            &3.into()
+        );
         list.push(33.into());
-        assert_eq!(list.step_past(win.clone()).unwrap(), &33.into());
+        assert_eq!(
+            list.step_past(win.clone(), 1, false, |_, _| true).unwrap(),
+            &33.into()
+        );
     }
 
     #[nvim_oxi::test]
@@ -539,12 +1242,24 @@ mod tests {
         list.push(3.into());
 
         let win = get_current_win();
-        assert_eq!(list.step_past(win.clone()).unwrap(), &3.into());
-        assert_eq!(list.step_past(win.clone()).unwrap(), &2.into());
-        assert_eq!(list.step_past(win.clone()).unwrap(), &1.into());
+        assert_eq!(
+            list.step_past(win.clone(), 1, false, |_, _| true).unwrap(),
+            &3.into()
+        );
+        assert_eq!(
+            list.step_past(win.clone(), 1, false, |_, _| true).unwrap(),
+            &2.into()
+        );
+        assert_eq!(
+            list.step_past(win.clone(), 1, false, |_, _| true).unwrap(),
+            &1.into()
+        );
 
         list.push(0.into());
-        assert_eq!(list.step_past(win.clone()).unwrap(), &0.into());
+        assert_eq!(
+            list.step_past(win.clone(), 1, false, |_, _| true).unwrap(),
+            &0.into()
+        );
     }
 
     #[nvim_oxi::test]
@@ -553,12 +1268,26 @@ mod tests {
         list.push(1.into());
 
         let win = get_current_win();
-        assert_eq!(list.step_past(win.clone()).unwrap(), &1.into());
-        assert!(list.step_past(win.clone()).is_none());
-        assert_eq!(list.step_future(win.clone()).unwrap(), &1.into());
-        assert!(list.step_future(win.clone()).is_none());
-        assert!(list.step_future(win.clone()).is_none());
-        assert_eq!(list.step_past(win.clone()).unwrap(), &1.into());
+        assert_eq!(
+            list.step_past(win.clone(), 1, false, |_, _| true).unwrap(),
+            &1.into()
+        );
+        assert!(list.step_past(win.clone(), 1, false, |_, _| true).is_none());
+        assert_eq!(
+            list.step_future(win.clone(), 1, false, |_, _| true)
+                .unwrap(),
+            &1.into()
+        );
+        assert!(list
+            .step_future(win.clone(), 1, false, |_, _| true)
+            .is_none());
+        assert!(list
+            .step_future(win.clone(), 1, false, |_, _| true)
+            .is_none());
+        assert_eq!(
+            list.step_past(win.clone(), 1, false, |_, _| true).unwrap(),
+            &1.into()
+        );
     }
 
     #[nvim_oxi::test]
@@ -844,7 +1573,7 @@ mod tests {
         list.pos = None;
 
         let win = get_current_win();
-        assert_eq!(list.pop_past(win).unwrap(), popped);
+        assert_eq!(list.pop_past(win, 1, |_| true).unwrap().1, popped);
         assert_eq!(list.pos, None);
     }
 
@@ -858,7 +1587,7 @@ mod tests {
         list.pos = Some(1);
 
         let win = get_current_win();
-        assert_eq!(list.pop_past(win).unwrap(), popped);
+        assert_eq!(list.pop_past(win, 1, |_| true).unwrap().1, popped);
         assert_eq!(list.pos, Some(1));
     }
 
@@ -873,7 +1602,7 @@ mod tests {
         list.pos = None;
 
         let win = get_current_win();
-        assert!(list.pop_future(win).is_none());
+        assert!(list.pop_future(win, 1, |_| true).is_none());
         assert_eq!(list.pos, None);
     }
 
@@ -887,7 +1616,7 @@ mod tests {
         list.pos = Some(1);
 
         let win = get_current_win();
-        assert_eq!(list.pop_future(win).unwrap(), popped);
+        assert_eq!(list.pop_future(win, 1, |_| true).unwrap().1, popped);
         assert_eq!(list.pos, Some(0));
     }
 
@@ -901,7 +1630,7 @@ mod tests {
         list.pos = Some(2);
 
         let win = get_current_win();
-        assert_eq!(list.pop_future(win).unwrap(), popped);
+        assert_eq!(list.pop_future(win, 1, |_| true).unwrap().1, popped);
         assert_eq!(list.pos, Some(1));
     }
 
@@ -915,7 +1644,7 @@ mod tests {
         list.pos = Some(0);
 
         let win = get_current_win();
-        assert_eq!(list.pop_future(win).unwrap(), popped);
+        assert_eq!(list.pop_future(win, 1, |_| true).unwrap().1, popped);
         assert_eq!(list.pos, None);
     }
 
@@ -930,7 +1659,7 @@ mod tests {
         list.pos = None;
 
         let win = get_current_win();
-        assert_eq!(list.pop_past(win).unwrap(), popped);
+        assert_eq!(list.pop_past(win, 1, |_| true).unwrap().1, popped);
         assert_eq!(list.pos, Some(0));
     }
 
@@ -1005,4 +1734,122 @@ mod tests {
         assert!(list.get(4).unwrap().as_close_past);
         assert_eq!(list.pos, Some(2));
     }
+
+    #[nvim_oxi::test]
+    fn can_fork_and_switch_branches() {
+        let mut list = TrackList::<Stub>::default();
+        list.push(1.into());
+        list.push(2.into());
+        list.push(3.into()); // we are here, will be stashed
+
+        list.pos = Some(0);
+
+        list.fork();
+        list.push(4.into());
+
+        assert!(list.pos.is_none());
+        let want = VecDeque::<Stub>::from([4.into(), 2.into(), 1.into()]);
+        assert_eq!(list.ring, want);
+        assert_eq!(list.branches.len(), 1);
+        assert_eq!(list.branches.first().unwrap().fork, Some(RecordId(2)));
+
+        list.switch_branch(0).unwrap();
+
+        let want = VecDeque::<Stub>::from([3.into(), 2.into(), 1.into()]);
+        assert_eq!(list.ring, want);
+        assert_eq!(list.pos, Some(0));
+        assert!(list.get(0).unwrap().as_close_future);
+        let want = VecDeque::<Stub>::from([4.into()]);
+        assert_eq!(list.branches.first().unwrap().ring, want);
+    }
+
+    #[nvim_oxi::test]
+    fn removing_fork_record_moves_fork_to_older() {
+        let mut list = TrackList::<Stub>::default();
+        list.push(1.into());
+        list.push(2.into()); // the fork, will be removed
+        list.push(3.into()); // we are here, will be stashed
+
+        list.pos = Some(0);
+
+        list.fork();
+        list.push(4.into());
+
+        list.remove(0);
+        assert_eq!(list.branches.first().unwrap().fork, Some(RecordId(2)));
+
+        list.remove(0);
+        assert_eq!(list.branches.first().unwrap().fork, Some(RecordId(1)));
+
+        list.remove(0);
+        assert_eq!(list.branches.first().unwrap().fork, None);
+    }
+
+    #[nvim_oxi::test]
+    fn keeps_fork_when_records_get_rotated() {
+        let mut list = TrackList::<Stub>::default();
+        list.push(1.into());
+        list.push(2.into()); // the fork
+        list.push(3.into()); // we are here, will be stashed
+
+        list.pos = Some(0);
+
+        list.fork();
+        list.push(4.into());
+        list.make_close_past(2);
+
+        let want = VecDeque::<Stub>::from([1.into(), 4.into(), 2.into()]);
+        assert_eq!(list.ring, want);
+
+        list.switch_branch(0).unwrap();
+
+        let want = VecDeque::<Stub>::from([3.into(), 2.into()]);
+        assert_eq!(list.ring, want);
+        let want = VecDeque::<Stub>::from([1.into(), 4.into()]);
+        assert_eq!(list.branches.first().unwrap().ring, want);
+    }
+
+    #[test]
+    fn repoints_parked_positions_at_the_fork() {
+        let mut list = TrackList::<Stub>::default();
+        list.push(1.into());
+        list.push(2.into()); // the fork
+        list.push(3.into()); // parked here, will be stashed
+
+        list.pos = Some(0);
+        list.switch_cursor(1, 2);
+
+        list.fork();
+        assert_eq!(list.parked.get(&1), Some(&Some(RecordId(2))));
+
+        list.switch_cursor(2, 1);
+        assert_eq!(list.pos, Some(0));
+        assert_eq!(list.ring.front().unwrap(), &2.into());
+    }
+
+    #[test]
+    fn retaining_branches_moves_nested_forks() {
+        let mut list = TrackList::<Stub>::default();
+        list.branches.push(Branch {
+            fork: None,
+            ring: VecDeque::from([3.into(), 2.into()]),
+            branches: Vec::from([Branch {
+                fork: Some(RecordId(2)),
+                ring: VecDeque::from([5.into()]),
+                branches: Vec::new(),
+            }]),
+        });
+
+        list.retain_branches(|v, _| v.id != 2);
+        let b = list.branches.first().unwrap();
+        assert_eq!(b.ring, VecDeque::<Stub>::from([3.into()]));
+        assert_eq!(b.branches.first().unwrap().fork, None);
+
+        list.retain_branches(|v, _| v.id != 3);
+        assert_eq!(
+            list.iter_branches().map(|v| v.id).collect::<Vec<_>>(),
+            Vec::from([5])
+        );
+        assert_eq!(list.branches.first().unwrap().fork, None);
+    }
 }
@@ -1,45 +1,67 @@
 use super::{
-    frecency::FrecencyType,
+    frecency::{FrecencyScore, FrecencyType},
     load_session,
     record::LazyExtmark,
     save_session,
     track_list::{Active, IndicateCloseness, Mark},
-    Session, Tick,
+    work_session::group_work_sessions,
+    workspace::DEFAULT_LIST_NAME,
+    PersistentWorkspace, Session, Tick, WorkSession, Workspace,
 };
 use crate::{
-    common_types::CursorPosition,
-    config::get_config,
-    state::{Record, TrackList},
-    ui::{namespace::get_namespace, record_mark::RecordMarkTime},
+    common_types::{CursorPosition, Direction, Scope, Timestamp, Unit, VerticalDirection},
+    config::{get_config, to_time_delta, EvictionPolicy, PositionScope},
+    state::{changelist_records, mark_records, Record, RecordId, TrackList},
+    ui::{
+        namespace::get_namespace,
+        record_mark::{recreate_mark_time, RecordMarkTime},
+    },
     InputError, Result,
 };
 use std::{
-    collections::{HashMap, HashSet},
+    cmp::Reverse,
+    collections::{HashMap, HashSet, VecDeque},
     path::{Path, PathBuf},
     time::{Duration, Instant},
 };
 
+use anyhow::anyhow;
+use chrono::{DateTime, TimeDelta, Utc};
 use nvim_oxi::api::{
-    get_current_buf, get_current_win, get_mode, get_option_value,
-    opts::{GetExtmarksOpts, OptionOpts, OptionScope},
+    del_mark, get_current_buf, get_current_tabpage, get_current_win, get_mark, get_mode,
+    get_option_value,
+    opts::{GetExtmarksOpts, GetMarkOpts, OptionOpts, OptionScope, SetMarkOpts},
     types::{ExtmarkPosition, GotMode, Mode},
-    Buffer,
+    Buffer, TabPage, Window,
 };
 
 #[derive(Debug)]
 pub struct Tracker {
+    /// The list in use, the only one whose marks are rendered and new records go to
     pub list: TrackList<Record>,
+    pub list_name: String,
+    pub workspaces: Vec<Workspace>,
     latest_flush: std::time::Instant,
     visited_bufs: HashMap<Buffer, Tick>,
     latest_buf: Option<Buffer>,
+    /// Handle of the window or tab the list position currently belongs to
+    cursor_key: Option<i32>,
+    /// Popped records along with the indices they were at, the latest one in front
+    trash: VecDeque<(usize, Record)>,
+    /// Set when the session on the disk could not be read, so that it is never overwritten
+    session_unreadable: bool,
 }
 
 const INITIAL_CHANGEDTICK: Tick = Tick(2);
 
 impl Tracker {
     pub fn persist_state(&mut self, path: &Path) -> Result<()> {
+        if self.session_unreadable {
+            return Ok(());
+        }
+
         if self.latest_flush.elapsed() >= Duration::from_secs(5) {
-            save_session(Session::try_from(&self.list)?, path)?;
+            save_session(Session::try_from(&*self)?, path)?;
             self.latest_flush = Instant::now();
         }
 
@@ -73,11 +95,32 @@ impl Tracker {
             }
         }
 
+        if get_config().tracker.seed.enable
+            && !self.list.iter_from_future().any(|r| r.buf == curr_buf)
+        {
+            let _ = self.seed(&curr_buf);
+        }
+
         self.visited_bufs
             .insert(curr_buf.clone(), INITIAL_CHANGEDTICK);
         None
     }
 
+    /// Fills the history of a buffer without records from its changelist and marks
+    fn seed(&mut self, buf: &Buffer) -> Result<()> {
+        let conf = &get_config().tracker.seed;
+
+        let mut records = Vec::new();
+        if conf.marks {
+            records.extend(mark_records(buf, false)?);
+        }
+        if conf.changelist {
+            records.extend(changelist_records(buf, conf.max_changes)?);
+        }
+
+        self.import(records)
+    }
+
     /// Unset the latest buf to skip the next change
     // TODO: it is a flaky method, because the changes might appear
     // after the special buffer is closed
@@ -85,7 +128,33 @@ impl Tracker {
         self.latest_buf = None
     }
 
+    /// Swaps in the list position of the current window or tab if they have their own ones,
+    /// has to be called before anything that reads or moves the position
+    pub fn sync_cursor(&mut self) {
+        let key = match get_config().tracker.history.position_scope {
+            PositionScope::Global => return,
+            PositionScope::Window => get_current_win().handle(),
+            PositionScope::Tab => get_current_tabpage().handle(),
+        };
+
+        match self.cursor_key.replace(key) {
+            Some(old) if old != key => self.list.switch_cursor(old, key),
+            _ => {}
+        }
+    }
+
+    /// Forgets the positions of closed windows and tabs
+    fn prune_cursors(&mut self) {
+        let scope = get_config().tracker.history.position_scope;
+        self.list.parked.retain(|&h, _| match scope {
+            PositionScope::Global => false,
+            PositionScope::Window => Window::from(h).is_valid(),
+            PositionScope::Tab => TabPage::from(h).is_valid(),
+        });
+    }
+
     pub fn track(&mut self) -> Result<()> {
+        self.sync_cursor();
         let buf_new = get_current_buf();
 
         // Skip special buffers
@@ -152,13 +221,11 @@ impl Tracker {
         let pos_new: CursorPosition = get_current_win().get_cursor()?.into();
 
         self.activate_first()?;
-        if let Some(i) = self.list.iter_from_future().position(
-            |Record {
-                 buf, lazy_extmark, ..
-             }| {
-                buf_new == *buf && { lazy_extmark.pos(buf_new.clone()).is_nearby(&pos_new) }
-            },
-        ) {
+        if let Some(i) = self.list.iter_from_future().position(|r| {
+            r.buf == buf_new
+                && r.follows_changes()
+                && r.lazy_extmark.pos(buf_new.clone()).is_nearby(&pos_new)
+        }) {
             if let Some(nearby_record) = self.list.get_mut(i) {
                 nearby_record.deact_update(
                     buf_new,
@@ -173,11 +240,65 @@ impl Tracker {
         };
 
         let record_new = Record::try_new_inactive(buf_new, tick_new.into(), pos_new)?;
+        self.fork()?;
         self.list.push_inactive(record_new);
 
         Ok(())
     }
 
+    pub fn push(&mut self, record: Record) -> Result<()> {
+        self.sync_cursor();
+        self.fork()?;
+        self.list.push(record);
+        Ok(())
+    }
+
+    /// Stashes the records in the future as a branch when branching history is enabled
+    fn fork(&mut self) -> Result<()> {
+        if !get_config().tracker.history.branching {
+            return Ok(());
+        }
+        let Some(p) = self.list.pos else {
+            return Ok(());
+        };
+
+        for r in self.list.iter_mut_from_future().take(p + 1) {
+            r.unload_extmark()?;
+        }
+        self.list.fork();
+
+        Ok(())
+    }
+
+    /// Makes the chosen branch the current line of records, placing us at its fork
+    pub fn switch_branch(&mut self, idx: usize) -> Result<()> {
+        self.sync_cursor();
+        let len_top = self
+            .list
+            .len_past_fork(idx)
+            .ok_or_else(|| InputError::NoRecords(format!("no branch with index {}", idx)))?;
+
+        for r in self.list.iter_mut_from_future().take(len_top) {
+            r.unload_extmark()?;
+        }
+
+        self.list.switch_branch(idx);
+        // The indices the popped records were at belong to the line we switched from
+        self.trash.clear();
+
+        let len_branch = self.list.pos.map(|p| p + 1).unwrap_or(0);
+        for r in self
+            .list
+            .iter_mut_from_future()
+            .take(len_branch)
+            .filter(|r| r.buf.is_loaded())
+        {
+            r.load_extmark()?;
+        }
+
+        Ok(())
+    }
+
     /// Assumes there is always at most a single inactive mark
     pub fn activate_first(&mut self) -> Result<()> {
         if let Some(i) = self.list.iter_from_future().position(|r| !r.is_active()) {
@@ -192,24 +313,41 @@ impl Tracker {
         Ok(())
     }
 
+    /// Returns the index of the record nearby the position that is the closest to it
+    pub fn closest_record_idx(&self, buf: &Buffer, pos: &CursorPosition) -> Option<usize> {
+        self.list
+            .iter_from_future()
+            .enumerate()
+            .filter(|(_, r)| r.buf == *buf)
+            .map(|(i, r)| (i, r.lazy_extmark.pos(buf.clone())))
+            .filter(|(_, p)| p.is_nearby(pos))
+            .min_by_key(|(_, p)| (p.line.abs_diff(pos.line), p.col.abs_diff(pos.col)))
+            .map(|(i, _)| i)
+    }
+
     /// Merges closely placed marks into a single one by removing the older ones.
     /// HACK: Kinda necessary because of an existing race condition that might occur
     /// on, let's say, a continuous undo, where new adjacent marks will be created.
     /// In a perfect world this should be optional.
+    /// Pinned records are left out, so the changes next to them keep their own records
     fn merge(&mut self, buf: Buffer) -> Result<()> {
         let mut del_indices = Vec::new();
         for (i, r) in self
             .list
             .iter_from_future()
             .enumerate()
-            .filter(|(_, r)| r.buf == buf)
+            .filter(|(_, r)| r.buf == buf && r.follows_changes() && r.slot.is_none())
         {
             let pos = r.lazy_extmark.pos(buf.clone());
             if self
                 .list
                 .iter_from_past()
                 .take(self.list.len() - i - 1)
-                .any(|r| r.buf == buf && r.lazy_extmark.pos(buf.clone()).is_nearby(&pos))
+                .any(|r| {
+                    r.buf == buf
+                        && r.follows_changes()
+                        && r.lazy_extmark.pos(buf.clone()).is_nearby(&pos)
+                })
             {
                 del_indices.push(i);
             }
@@ -221,6 +359,19 @@ impl Tracker {
             }
         }
 
+        // Records in the branches are merged into the older ones of the same branch
+        self.list.retain_branches(|r, mut older| {
+            let pos = r.lazy_extmark.pos(buf.clone());
+            !(r.buf == buf
+                && r.follows_changes()
+                && r.slot.is_none()
+                && older.any(|o| {
+                    o.buf == buf
+                        && o.follows_changes()
+                        && o.lazy_extmark.pos(buf.clone()).is_nearby(&pos)
+                }))
+        });
+
         Ok(())
     }
 
@@ -247,12 +398,17 @@ impl Tracker {
         Ok(())
     }
 
+    /// Records in the lists set aside and in the branches included
     fn remove_deleted_file_records(&mut self) -> Result<()> {
         let existing_bufs: Vec<Buffer> = {
             let bufs = self
-                .list
-                .iter_from_future()
-                .map(|r| r.buf.clone())
+                .lists_mut()
+                .flat_map(|l| {
+                    l.iter_from_future()
+                        .chain(l.iter_branches())
+                        .map(|r| r.buf.clone())
+                        .collect::<Vec<Buffer>>()
+                })
                 .collect::<HashSet<Buffer>>()
                 .into_iter()
                 .collect::<Vec<Buffer>>();
@@ -267,21 +423,85 @@ impl Tracker {
                 .collect()
         };
 
-        let del_indices: Vec<usize> = self
+        for list in self.lists_mut() {
+            let del_indices: Vec<usize> = list
+                .iter_from_future()
+                .enumerate()
+                .filter_map(|(i, r)| -> Option<usize> {
+                    if !r.pinned && !existing_bufs.contains(&r.buf) {
+                        Some(i)
+                    } else {
+                        None
+                    }
+                })
+                .collect();
+
+            for i in del_indices.into_iter().rev() {
+                list.remove(i);
+            }
+            list.retain_branches(|r, _| r.pinned || existing_bufs.contains(&r.buf));
+        }
+
+        Ok(())
+    }
+
+    /// The list in use followed by the ones set aside
+    fn lists_mut(&mut self) -> impl Iterator<Item = &mut TrackList<Record>> {
+        std::iter::once(&mut self.list).chain(self.workspaces.iter_mut().map(|w| &mut w.list))
+    }
+
+    /// Flags records whose lines were rewritten since placing them
+    /// and evicts the ones that have been stale for too long
+    fn handle_stale(&mut self, buf: Buffer) -> Result<()> {
+        let conf = &get_config().tracker.stale;
+        if !conf.enable {
+            return Ok(());
+        }
+
+        let pos = self.list.pos;
+        for (i, r) in self
             .list
-            .iter_from_future()
+            .iter_mut_from_future()
             .enumerate()
-            .filter_map(|(i, r)| -> Option<usize> {
-                if !existing_bufs.contains(&r.buf) {
-                    Some(i)
-                } else {
-                    None
+            .filter(|(_, r)| r.buf == buf)
+        {
+            r.check_stale(recreate_mark_time(i, pos))?;
+        }
+
+        // Out of range values are rejected by the config already
+        let Some(evict_after) = conf.evict_after_seconds.and_then(to_time_delta) else {
+            return Ok(());
+        };
+        // Records set aside keep the staleness they had when they got stashed
+        let expired = |r: &Record| {
+            !r.pinned
+                && r.slot.is_none()
+                && r.stale_since.is_some_and(|t| {
+                    Utc::now().signed_duration_since(Into::<DateTime<Utc>>::into(t)) > evict_after
+                })
+        };
+        for list in self.lists_mut() {
+            let del_indices: Vec<usize> = list
+                .iter_from_future()
+                .enumerate()
+                .filter_map(|(i, r)| expired(r).then_some(i))
+                .collect();
+
+            for i in del_indices.into_iter().rev() {
+                if let Some(r) = list.remove(i) {
+                    r.lazy_extmark.delete(r.buf)?;
                 }
-            })
-            .collect();
+            }
+            list.retain_branches(|r, _| !expired(r));
+        }
 
-        for i in del_indices.into_iter().rev() {
-            self.list.remove(i);
+        Ok(())
+    }
+
+    /// Keeps every list within the configured bounds on its own
+    fn evict(&mut self) -> Result<()> {
+        for list in self.lists_mut() {
+            evict(list)?;
         }
 
         Ok(())
@@ -291,7 +511,50 @@ impl Tracker {
         self.remove_deleted_file_records()?;
         let buf_curr = get_current_buf();
         self.merge(buf_curr.clone())?;
+        self.handle_stale(buf_curr.clone())?;
+        self.evict()?;
         self.delete_leaked_extmarks(buf_curr)?;
+        self.prune_cursors();
+        self.mirror_global_marks()?;
+        Ok(())
+    }
+
+    /// Points the configured global marks at the most frecent records in the loaded buffers
+    fn mirror_global_marks(&self) -> Result<()> {
+        let conf = &get_config().tracker.global_marks;
+        if !conf.enable {
+            return Ok(());
+        }
+
+        let hottest: Vec<&Record> = self
+            .list
+            .frecency()
+            .into_iter()
+            .rev()
+            .map(|(_, r)| r)
+            .filter(|r| r.buf.is_loaded())
+            .take(conf.names.len())
+            .collect();
+        for (&name, r) in conf.names.iter().zip(&hottest) {
+            let (line, col) = r.lazy_extmark.pos(r.buf.clone()).mark_pos();
+            if get_mark(name, &GetMarkOpts::builder().build())
+                .is_ok_and(|(l, c, b, _)| l == line && c == col && b == r.buf)
+            {
+                continue;
+            }
+
+            r.buf
+                .clone()
+                .set_mark(name, line, col, &SetMarkOpts::builder().build())?;
+        }
+
+        // the names left without a record would otherwise keep pointing at their old positions
+        for &name in conf.names.iter().skip(hottest.len()) {
+            if get_mark(name, &GetMarkOpts::builder().build()).is_ok_and(|(l, _, _, _)| l != 0) {
+                del_mark(name)?;
+            }
+        }
+
         Ok(())
     }
 
@@ -310,13 +573,148 @@ impl Tracker {
         Ok(())
     }
 
+    /// Errors when there is a session that could not be read, in which case it is left untouched
     pub fn load_state(&mut self, path: &Path) -> Result<()> {
-        self.list = load_session(path).unwrap_or_default();
+        if !path.exists() {
+            return Ok(());
+        }
+
+        let Session {
+            data,
+            name,
+            workspaces,
+            ..
+        } = load_session(path).map_err(|e| {
+            self.session_unreadable = true;
+            anyhow!(
+                "could not read the session at {}, it will not be overwritten: {}",
+                path.display(),
+                e
+            )
+        })?;
+
+        self.list = data.try_into().unwrap_or_default();
+        if !name.is_empty() {
+            self.list_name = name;
+        }
+        self.workspaces = workspaces
+            .into_iter()
+            .filter_map(|PersistentWorkspace { name, data }| {
+                Some(Workspace {
+                    name,
+                    list: data.try_into().ok()?,
+                })
+            })
+            .collect();
+
+        Ok(())
+    }
+
+    fn check_list_name(&self, name: &str) -> Result<()> {
+        if name.is_empty() {
+            Err(InputError::FunctionArguments(
+                "list name can not be empty".to_owned(),
+            ))?
+        }
+        if self.list_name == name || self.workspaces.iter().any(|w| w.name == name) {
+            Err(InputError::FunctionArguments(format!(
+                "list named {} already exists",
+                name
+            )))?
+        }
+
         Ok(())
     }
 
-    pub fn step_past(&mut self) -> Result<()> {
-        let Some(record) = self.list.step_past(get_current_win()) else {
+    /// Sets the current list aside and starts recording into a new empty one
+    pub fn new_list(&mut self, name: String) -> Result<()> {
+        self.check_list_name(&name)?;
+        self.swap_list(Workspace {
+            name,
+            list: TrackList::default(),
+        })
+    }
+
+    pub fn switch_list(&mut self, name: &str) -> Result<()> {
+        if self.list_name == name {
+            return Ok(());
+        }
+        let idx = self
+            .workspaces
+            .iter()
+            .position(|w| w.name == name)
+            .ok_or_else(|| InputError::NoRecords(format!("no list named {}", name)))?;
+
+        let workspace = self.workspaces.remove(idx);
+        self.swap_list(workspace)
+    }
+
+    /// Deleting the list in use switches to the most recently used other one
+    pub fn delete_list(&mut self, name: &str) -> Result<()> {
+        if self.list_name != name {
+            let idx = self
+                .workspaces
+                .iter()
+                .position(|w| w.name == name)
+                .ok_or_else(|| InputError::NoRecords(format!("no list named {}", name)))?;
+            self.workspaces.remove(idx);
+            return Ok(());
+        }
+
+        let next = self.workspaces.pop().unwrap_or_else(|| Workspace {
+            name: DEFAULT_LIST_NAME.to_owned(),
+            list: TrackList::default(),
+        });
+        self.swap_list(next)?;
+        self.workspaces.pop();
+
+        Ok(())
+    }
+
+    pub fn rename_list(&mut self, name: String) -> Result<()> {
+        self.check_list_name(&name)?;
+        self.list_name = name;
+        Ok(())
+    }
+
+    /// Puts the list in use aside and renders the marks of the provided one instead
+    fn swap_list(&mut self, Workspace { name, list }: Workspace) -> Result<()> {
+        for r in self.list.iter_mut_from_future() {
+            r.unload_extmark()?;
+        }
+
+        let old = Workspace {
+            name: std::mem::replace(&mut self.list_name, name),
+            list: std::mem::replace(&mut self.list, list),
+        };
+        self.workspaces.push(old);
+        // Indices of the popped records only make sense in the list they were popped from
+        self.trash.clear();
+
+        for r in self
+            .list
+            .iter_mut_from_future()
+            .filter(|r| r.is_active() && r.buf.is_loaded())
+        {
+            r.load_extmark()?;
+        }
+        self.list.refresh_closeness();
+
+        Ok(())
+    }
+
+    pub fn step_past(&mut self, count: usize, scope: Scope, unit: Unit, wrap: bool) -> Result<()> {
+        self.sync_cursor();
+        if unit == Unit::Session {
+            return self.step_work_session(Direction::Back, count, scope, wrap);
+        }
+        let (buf, win) = (get_current_buf(), get_current_win());
+        let Some(record) = self.list.step_past(
+            win.clone(),
+            count,
+            wrap,
+            step_filter(scope, unit, &buf, &win),
+        ) else {
             return Ok(());
         };
         record.frecency.add_record(FrecencyType::RelativeGoto);
@@ -326,18 +724,107 @@ impl Tracker {
         Ok(())
     }
 
-    pub fn step_future(&mut self) -> Result<()> {
+    pub fn step_future(
+        &mut self,
+        count: usize,
+        scope: Scope,
+        unit: Unit,
+        wrap: bool,
+    ) -> Result<()> {
+        self.sync_cursor();
+        if unit == Unit::Session {
+            return self.step_work_session(Direction::Forward, count, scope, wrap);
+        }
         if let Some(r) = self.list.iter_mut_from_future().find(|r| !r.is_active()) {
             r.load_extmark()?;
         }
-        let Some(record) = self.list.step_future(get_current_win()) else {
+        let (buf, win) = (get_current_buf(), get_current_win());
+        let Some(record) = self.list.step_future(
+            win.clone(),
+            count,
+            wrap,
+            step_filter(scope, unit, &buf, &win),
+        ) else {
+            return Ok(());
+        };
+        record.frecency.add_record(FrecencyType::RelativeGoto);
+        Ok(())
+    }
+
+    /// Work sessions of the active records passing the filter, the latest one goes first
+    pub fn work_sessions(&self, filter: impl Fn(&Record) -> bool) -> Vec<WorkSession> {
+        group_work_sessions(
+            &self.list,
+            get_config().tracker.work_sessions.idle_gap_minutes * 60,
+            filter,
+        )
+    }
+
+    /// Jumps to the earliest record of the work session `count` sessions away from the current one
+    fn step_work_session(
+        &mut self,
+        direction: Direction,
+        count: usize,
+        scope: Scope,
+        wrap: bool,
+    ) -> Result<()> {
+        let (buf, win) = (get_current_buf(), get_current_win());
+        let sessions = self.work_sessions(|r| r.in_scope(scope, &buf, &win));
+        if sessions.is_empty() {
+            return Ok(());
+        }
+
+        // Outside of the list we are still in the latest session unless it has already ended
+        let gap = get_config().tracker.work_sessions.idle_gap_minutes * 60;
+        let current = match self.list.pos {
+            Some(p) => sessions.iter().position(|s| s.contains(p)),
+            None => sessions
+                .first()
+                .filter(|s| Utc::now().timestamp() - i64::from(s.ended) < gap)
+                .map(|_| 0),
+        };
+
+        let len = sessions.len() as isize;
+        let curr = current.map_or(-1, |c| c as isize);
+        let target = match direction {
+            Direction::Back => curr + count as isize,
+            Direction::Forward => curr - count as isize,
+        };
+        let target = match wrap {
+            true => Some(target.rem_euclid(len)),
+            false if target >= len && curr < len - 1 => Some(len - 1),
+            false => (0..len).contains(&target).then_some(target),
+        };
+        let Some(idx) = target
+            .and_then(|t| sessions.get(t as usize))
+            .map(WorkSession::first)
+        else {
+            return Ok(());
+        };
+
+        let Some(record) = self.list.step_to(win, idx)? else {
             return Ok(());
         };
         record.frecency.add_record(FrecencyType::RelativeGoto);
         Ok(())
     }
 
+    /// Jumps to the earliest record of the chosen work session, the latest session is the first one
+    pub fn goto_work_session(&mut self, idx_session: usize) -> Result<()> {
+        self.sync_cursor();
+        let sessions = self.work_sessions(|_| true);
+        let session = sessions.get(idx_session).ok_or_else(|| {
+            InputError::NoRecords(format!("non-existent work session: {}", idx_session))
+        })?;
+
+        if let Some(record) = self.list.step_to(get_current_win(), session.first())? {
+            record.frecency.add_record(FrecencyType::AbsoluteGoto);
+        }
+        Ok(())
+    }
+
     pub fn goto_absolute(&mut self, idx_record: usize) -> Result<()> {
+        self.sync_cursor();
         self.activate_first()?;
         let record = self.list.get_mut(idx_record).ok_or_else(|| {
             InputError::NoRecords(format!(
@@ -350,23 +837,235 @@ impl Tracker {
         Ok(())
     }
 
-    pub fn pop_past(&mut self) -> Result<()> {
-        let Some(mut record) = self.list.pop_past(get_current_win()) else {
+    /// Jumps to the closest record below or above the cursor line in the current buffer
+    pub fn goto_nearest(&mut self, direction: VerticalDirection, wrap: bool) -> Result<()> {
+        self.sync_cursor();
+        let buf = get_current_buf();
+        let CursorPosition { line, .. } = get_current_win().get_cursor()?.into();
+
+        self.activate_first()?;
+        let lines: Vec<(usize, usize)> = self
+            .list
+            .iter_from_future()
+            .enumerate()
+            .filter(|(_, r)| r.buf == buf && r.is_active())
+            .map(|(i, r)| (i, r.lazy_extmark.pos(buf.clone()).line))
+            .collect();
+
+        let nearest = match direction {
+            VerticalDirection::Down => lines
+                .iter()
+                .filter(|(_, l)| *l > line)
+                .min_by_key(|(_, l)| *l)
+                .or_else(|| lines.iter().filter(|_| wrap).min_by_key(|(_, l)| *l)),
+            VerticalDirection::Up => lines
+                .iter()
+                .filter(|(_, l)| *l < line)
+                .max_by_key(|(_, l)| *l)
+                .or_else(|| lines.iter().filter(|_| wrap).max_by_key(|(_, l)| *l)),
+        };
+
+        match nearest {
+            Some(&(i, _)) => self.goto_absolute(i),
+            None => Ok(()),
+        }
+    }
+
+    /// Finds the record whose latest visit is the closest in time to the given moment
+    pub fn closest_in_time_idx(&self, timestamp: Timestamp, scope: Scope) -> Option<usize> {
+        let buf = get_current_buf();
+        let win = get_current_win();
+        let target: i64 = timestamp.into();
+
+        self.list
+            .iter_from_future()
+            .enumerate()
+            .filter(|(_, r)| r.in_scope(scope, &buf, &win))
+            .min_by_key(|(_, r)| Into::<i64>::into(r.frecency.latest_timestamp()).abs_diff(target))
+            .map(|(i, _)| i)
+    }
+
+    /// Binds the record to the numbered slot, taking the slot away from whichever record held it
+    pub fn bind_slot(&mut self, idx_record: usize, slot: Option<u8>) -> Result<()> {
+        self.sync_cursor();
+        let pos = self.list.pos;
+        for (i, r) in self.list.iter_mut_from_future().enumerate() {
+            if i == idx_record {
+                r.set_slot(slot, recreate_mark_time(i, pos))?;
+            } else if slot.is_some() && r.slot == slot {
+                r.set_slot(None, recreate_mark_time(i, pos))?;
+            }
+        }
+
+        Ok(())
+    }
+
+    pub fn goto_slot(&mut self, slot: u8) -> Result<()> {
+        self.sync_cursor();
+        let idx_record = self
+            .list
+            .iter_from_future()
+            .position(|r| r.slot == Some(slot))
+            .ok_or_else(|| InputError::NoRecords(format!("no record bound to slot {}", slot)))?;
+        self.goto_absolute(idx_record)
+    }
+
+    /// Lists the slots in use in ascending order as `n=` arguments
+    pub fn slot_completion(&self) -> Vec<String> {
+        let mut slots: Vec<u8> = self
+            .list
+            .iter_from_future()
+            .filter_map(|r| r.slot)
+            .collect();
+        slots.sort_unstable();
+        slots.into_iter().map(|n| format!("n={}", n)).collect()
+    }
+
+    pub fn pop_past(&mut self, count: usize, scope: Scope) -> Result<()> {
+        self.sync_cursor();
+        let (buf, win) = (get_current_buf(), get_current_win());
+        let Some((idx, record)) = self
+            .list
+            .pop_past(win.clone(), count, |r| r.in_scope(scope, &buf, &win))
+        else {
             return Ok(());
         };
-        record.delete()?;
+        self.throw_away(idx, record)?;
         if let Some(r) = self.list.iter_mut_from_future().find(|r| !r.is_active()) {
             r.load_extmark()?;
         }
         Ok(())
     }
 
-    pub fn pop_future(&mut self) -> Result<()> {
+    pub fn pop_future(&mut self, count: usize, scope: Scope) -> Result<()> {
+        self.sync_cursor();
         self.activate_first()?;
-        let Some(mut record) = self.list.pop_future(get_current_win()) else {
+        let (buf, win) = (get_current_buf(), get_current_win());
+        let Some((idx, record)) = self
+            .list
+            .pop_future(win.clone(), count, |r| r.in_scope(scope, &buf, &win))
+        else {
             return Ok(());
         };
-        record.delete()
+        self.throw_away(idx, record)
+    }
+
+    /// Removes the record under the index, jumping to it first if asked to
+    pub fn pop_absolute(&mut self, idx_record: usize, jump: bool) -> Result<()> {
+        self.sync_cursor();
+        self.activate_first()?;
+        if jump {
+            if let Some(r) = self.list.get_mut(idx_record) {
+                r.jump(get_current_win())?;
+            }
+        }
+
+        let record = self.list.remove(idx_record).ok_or_else(|| {
+            InputError::NoRecords(format!(
+                "non-existent index for absolute pop provided: {}",
+                idx_record
+            ))
+        })?;
+        self.throw_away(idx_record, record)
+    }
+
+    pub fn reorder(&mut self, from: usize, to: usize) -> Result<()> {
+        self.sync_cursor();
+        self.list.reorder(from, to).ok_or_else(|| {
+            InputError::NoRecords(format!(
+                "can't move the record from {} to {} in the list of {}",
+                from,
+                to,
+                self.list.len()
+            ))
+        })?;
+        Ok(())
+    }
+
+    /// Adds the records given oldest first, skipping the ones nearby the existing records
+    pub fn import(&mut self, records: impl IntoIterator<Item = Record>) -> Result<()> {
+        self.sync_cursor();
+        self.activate_first()?;
+        for mut r in records {
+            let pos = r.lazy_extmark.pos(r.buf.clone());
+            if self.closest_record_idx(&r.buf, &pos).is_some() {
+                continue;
+            }
+
+            if r.buf.is_loaded() {
+                r.load_extmark()?;
+            }
+            self.push(r)?;
+        }
+
+        Ok(())
+    }
+
+    /// Removes every record passing the filter along with its mark, the ones stashed
+    /// in the branches included. Pinned records and the ones bound to slots are left out
+    pub fn clear(&mut self, filter: impl Fn(&Record) -> bool) -> Result<()> {
+        self.sync_cursor();
+        let clears = |r: &Record| !r.pinned && r.slot.is_none() && filter(r);
+
+        let del_indices: Vec<usize> = self
+            .list
+            .iter_from_future()
+            .enumerate()
+            .filter(|(_, r)| clears(r))
+            .map(|(i, _)| i)
+            .collect();
+
+        for i in del_indices.into_iter().rev() {
+            if let Some(r) = self.list.remove(i) {
+                r.lazy_extmark.delete(r.buf)?;
+            }
+        }
+
+        let mut del_marks = Vec::new();
+        self.list.retain_branches(|r, _| {
+            if clears(r) {
+                del_marks.push((r.buf.clone(), r.lazy_extmark.clone()));
+                return false;
+            }
+            true
+        });
+        for (buf, e) in del_marks {
+            e.delete(buf)?;
+        }
+
+        Ok(())
+    }
+
+    /// Removes the popped record's mark and keeps the record to be restored later
+    fn throw_away(&mut self, idx: usize, mut record: Record) -> Result<()> {
+        record.unload_extmark()?;
+
+        let size = get_config().tracker.history.trash_size;
+        self.trash.push_front((idx, record));
+        self.trash.truncate(size);
+        Ok(())
+    }
+
+    /// Puts the most recently popped record back where it was, skipping the ones
+    /// whose buffers have been deleted since
+    pub fn restore(&mut self) -> Result<()> {
+        self.sync_cursor();
+        let (idx, mut record) = loop {
+            let Some((idx, record)) = self.trash.pop_front() else {
+                Err(InputError::NoRecords(
+                    "no popped records to restore".to_owned(),
+                ))?
+            };
+            if record.buf.is_valid() {
+                break (idx, record);
+            }
+        };
+
+        if record.is_active() && record.buf.is_loaded() {
+            record.load_extmark()?;
+        }
+        self.list.insert(idx, record);
+        Ok(())
     }
 }
 
@@ -374,9 +1073,141 @@ impl Default for Tracker {
     fn default() -> Self {
         Self {
             list: TrackList::default(),
+            list_name: DEFAULT_LIST_NAME.to_owned(),
+            workspaces: Vec::default(),
             latest_flush: Instant::now(),
             visited_bufs: HashMap::default(),
             latest_buf: None,
+            cursor_key: None,
+            trash: VecDeque::default(),
+            session_unreadable: false,
         }
     }
 }
+
+/// Checks whether a record can be stepped on when moving from the previous one,
+/// or from the current buffer for the first step
+fn step_filter<'a>(
+    scope: Scope,
+    unit: Unit,
+    buf: &'a Buffer,
+    win: &'a Window,
+) -> impl Fn(&Record, Option<&Record>) -> bool + 'a {
+    move |r, from| {
+        r.in_scope(scope, buf, win)
+            && match unit {
+                Unit::Record | Unit::Session => true,
+                Unit::File => r.buf != *from.map(|f| &f.buf).unwrap_or(buf),
+            }
+    }
+}
+
+/// Keeps the history of the list within the configured bounds
+/// by evicting records according to the chosen policy, the ones stashed in the branches included
+fn evict(list: &mut TrackList<Record>) -> Result<()> {
+    let conf = &get_config().tracker.history;
+    let len_line = list.len();
+
+    let (del_line, del_ids) = {
+        // Records of the current line come first, followed by the ones in the branches
+        let records: Vec<&Record> = list
+            .iter_from_future()
+            .chain(list.iter_branches())
+            .collect();
+        // Inactive ones are still being worked on, pinned and slotted ones are kept on purpose
+        let evictable = || {
+            records
+                .iter()
+                .copied()
+                .enumerate()
+                .filter(|(_, r)| r.is_active() && !r.pinned && r.slot.is_none())
+        };
+        let mut del_indices: HashSet<usize> = HashSet::new();
+
+        // Out of range values are rejected by the config already
+        if let Some(max_age) = conf
+            .max_age_days
+            .and_then(|d| TimeDelta::try_days(d.into()))
+        {
+            del_indices.extend(evictable().filter_map(|(i, r)| {
+                (Utc::now().signed_duration_since(Into::<DateTime<Utc>>::into(
+                    r.frecency.latest_timestamp(),
+                )) > max_age)
+                    .then_some(i)
+            }));
+        }
+
+        if let Some(max) = conf.max_records_per_buf {
+            let bufs: HashSet<Buffer> = records.iter().map(|r| r.buf.clone()).collect();
+            for buf in bufs {
+                let count = records
+                    .iter()
+                    .enumerate()
+                    .filter(|(i, r)| r.buf == buf && !del_indices.contains(i))
+                    .count();
+                let candidates = evictable()
+                    .filter(|(i, r)| r.buf == buf && !del_indices.contains(i))
+                    .collect();
+                del_indices.extend(pick_evicted(
+                    conf.eviction,
+                    candidates,
+                    count.saturating_sub(max),
+                ));
+            }
+        }
+
+        if let Some(max) = conf.max_records {
+            let count = records.len() - del_indices.len();
+            let candidates = evictable()
+                .filter(|(i, _)| !del_indices.contains(i))
+                .collect();
+            del_indices.extend(pick_evicted(
+                conf.eviction,
+                candidates,
+                count.saturating_sub(max),
+            ));
+        }
+
+        let mut del_line: Vec<usize> = del_indices
+            .iter()
+            .copied()
+            .filter(|&i| i < len_line)
+            .collect();
+        del_line.sort_unstable();
+        let del_ids: HashSet<RecordId> = del_indices
+            .into_iter()
+            .filter(|&i| i >= len_line)
+            .filter_map(|i| records.get(i).map(|r| r.id))
+            .collect();
+
+        (del_line, del_ids)
+    };
+
+    for i in del_line.into_iter().rev() {
+        if let Some(r) = list.remove(i) {
+            r.lazy_extmark.delete(r.buf)?;
+        }
+    }
+    list.retain_branches(|r, _| !del_ids.contains(&r.id));
+
+    Ok(())
+}
+
+/// Picks indices of the first `count` records to go according to the policy,
+/// ties are broken in favour of keeping the newer records
+fn pick_evicted(
+    policy: EvictionPolicy,
+    mut candidates: Vec<(usize, &Record)>,
+    count: usize,
+) -> Vec<usize> {
+    match policy {
+        EvictionPolicy::Frecency => candidates.sort_by_key(|&(i, r)| (r.total_score(), Reverse(i))),
+        EvictionPolicy::Oldest => candidates
+            .sort_by_key(|&(i, r)| (i64::from(r.frecency.created_timestamp()), Reverse(i))),
+        EvictionPolicy::Lru => {
+            candidates.sort_by_key(|&(i, r)| (i64::from(r.frecency.latest_timestamp()), Reverse(i)))
+        }
+    };
+
+    candidates.into_iter().take(count).map(|(i, _)| i).collect()
+}
@@ -0,0 +1,151 @@
+use super::{ChangeTypeRecord, PlaceTypeRecord, Record};
+use crate::Result;
+
+use anyhow::anyhow;
+use nvim_oxi::{
+    api::{call_function, Buffer},
+    Array, Object,
+};
+use serde::{de::DeserializeOwned, Deserialize};
+
+fn call_deserialize<T: DeserializeOwned>(func: &str, args: Array) -> Result<T> {
+    let obj: Object = call_function(func, args)?;
+    Ok(T::deserialize(nvim_oxi::serde::Deserializer::new(obj))
+        .map_err(|e| anyhow!("could not read the result of {}(): {}", func, e))?)
+}
+
+/// Entry of `getjumplist()` and `getchangelist()`, with the line being 1 and the column 0 indexed
+#[derive(Deserialize)]
+struct ListEntry {
+    #[serde(default)]
+    bufnr: Option<i32>,
+    lnum: usize,
+    col: usize,
+}
+
+/// Entry of `getmarklist()`, its position is `[bufnr, lnum, col, off]` with the column 1 indexed
+#[derive(Deserialize)]
+struct MarkEntry {
+    mark: String,
+    pos: (i32, usize, usize, i32),
+}
+
+/// Entry of `getqflist()` and `getloclist()`, with the column 1 indexed and 0 when unknown
+#[derive(Deserialize)]
+struct QuickfixEntry {
+    bufnr: i32,
+    lnum: usize,
+    col: usize,
+    valid: i32,
+    text: String,
+}
+
+/// Creates records out of the most recent entries of the current window's jumplist, oldest first
+pub fn jumplist_records(max: usize) -> Result<Vec<Record>> {
+    let (entries, _): (Vec<ListEntry>, usize) = call_deserialize("getjumplist", Array::new())?;
+
+    let skip = entries.len().saturating_sub(max);
+    Ok(entries
+        .into_iter()
+        .skip(skip)
+        .filter_map(|ListEntry { bufnr, lnum, col }| {
+            let buf: Buffer = bufnr?.into();
+            buf.is_valid()
+                .then(|| Record::new_unloaded(buf, PlaceTypeRecord::Jump, (lnum, col).into()))
+        })
+        .collect())
+}
+
+/// Creates records out of the most recent changes in the buffer, oldest first
+pub fn changelist_records(buf: &Buffer, max: usize) -> Result<Vec<Record>> {
+    let (entries, _): (Vec<ListEntry>, usize) =
+        call_deserialize("getchangelist", Array::from_iter([buf.handle()]))?;
+
+    let skip = entries.len().saturating_sub(max);
+    Ok(entries
+        .into_iter()
+        .skip(skip)
+        .map(|ListEntry { lnum, col, .. }| {
+            Record::new_unloaded(
+                buf.clone(),
+                PlaceTypeRecord::Change(ChangeTypeRecord::Restored),
+                (lnum, col).into(),
+            )
+        })
+        .collect())
+}
+
+/// Creates records out of the lettered marks set in the buffer, both the local and the global ones,
+/// including the global marks of the other files when `global` is set
+pub fn mark_records(buf: &Buffer, global: bool) -> Result<Vec<Record>> {
+    let local: Vec<MarkEntry> = call_deserialize("getmarklist", Array::from_iter([buf.handle()]))?;
+    let file: Vec<MarkEntry> = call_deserialize("getmarklist", Array::new())?;
+
+    Ok(local
+        .into_iter()
+        .chain(file)
+        .filter(|MarkEntry { mark, .. }| {
+            mark.strip_prefix('\'')
+                .is_some_and(|m| m.len() == 1 && m.chars().all(|c| c.is_ascii_alphabetic()))
+        })
+        .filter_map(
+            |MarkEntry {
+                 pos: (bufnr, lnum, col, _),
+                 ..
+             }| {
+                let buf_mark: Buffer = match bufnr {
+                    0 => buf.clone(),
+                    b => b.into(),
+                };
+                (buf_mark.is_valid() && (global || buf_mark == *buf)).then(|| {
+                    Record::new_unloaded(
+                        buf_mark,
+                        PlaceTypeRecord::Change(ChangeTypeRecord::Restored),
+                        (lnum, col.saturating_sub(1)).into(),
+                    )
+                })
+            },
+        )
+        .collect())
+}
+
+/// Creates records out of the valid entries of the quickfix list,
+/// or of the current window's location list, noting them with their text
+pub fn quickfix_records(loclist: bool) -> Result<Vec<Record>> {
+    let entries: Vec<QuickfixEntry> = match loclist {
+        false => call_deserialize("getqflist", Array::new())?,
+        true => call_deserialize("getloclist", Array::from_iter([0]))?,
+    };
+
+    Ok(entries
+        .into_iter()
+        .filter(
+            |QuickfixEntry {
+                 bufnr, lnum, valid, ..
+             }| *valid != 0 && *bufnr != 0 && *lnum != 0,
+        )
+        .filter_map(
+            |QuickfixEntry {
+                 bufnr,
+                 lnum,
+                 col,
+                 text,
+                 ..
+             }| {
+                let buf: Buffer = bufnr.into();
+                buf.is_valid().then(|| {
+                    let mut record = Record::new_unloaded(
+                        buf,
+                        PlaceTypeRecord::Quickfix,
+                        (lnum, col.saturating_sub(1)).into(),
+                    );
+                    let text = text.trim();
+                    if !text.is_empty() {
+                        record.note = Some(text.to_owned());
+                    }
+                    record
+                })
+            },
+        )
+        .collect())
+}
@@ -0,0 +1,93 @@
+use super::{track_list::Active, Record, TrackList};
+use crate::common_types::Timestamp;
+
+/// Records grouped together by not having long enough pauses in activity between them
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct WorkSession {
+    /// Indices of the records in the list ordered from the earliest to the latest
+    pub records: Vec<usize>,
+    pub started: Timestamp,
+    pub ended: Timestamp,
+}
+
+impl WorkSession {
+    /// Index of the earliest record of the session
+    pub fn first(&self) -> usize {
+        // Should never panic since a session is only created with a record
+        *self.records.first().unwrap()
+    }
+
+    pub fn contains(&self, idx: usize) -> bool {
+        self.records.contains(&idx)
+    }
+}
+
+/// Splits the active records passing the filter into work sessions separated
+/// by at least `gap_seconds` of inactivity, the latest session goes first
+pub fn group_work_sessions(
+    list: &TrackList<Record>,
+    gap_seconds: i64,
+    filter: impl Fn(&Record) -> bool,
+) -> Vec<WorkSession> {
+    let timed: Vec<(usize, i64)> = list
+        .iter_from_future()
+        .enumerate()
+        .filter(|(_, r)| r.is_active() && filter(r))
+        .map(|(i, r)| (i, r.frecency.latest_timestamp().into()))
+        .collect();
+
+    split_by_gaps(timed, gap_seconds)
+}
+
+fn split_by_gaps(mut timed: Vec<(usize, i64)>, gap_seconds: i64) -> Vec<WorkSession> {
+    timed.sort_by_key(|&(i, t)| (t, std::cmp::Reverse(i)));
+
+    let mut sessions: Vec<WorkSession> = Vec::new();
+    let mut prev: Option<i64> = None;
+    for (i, t) in timed {
+        match sessions.last_mut() {
+            Some(s) if prev.is_some_and(|p| t - p < gap_seconds) => {
+                s.records.push(i);
+                s.ended = t.into();
+            }
+            _ => sessions.push(WorkSession {
+                records: Vec::from([i]),
+                started: t.into(),
+                ended: t.into(),
+            }),
+        }
+        prev = Some(t);
+    }
+
+    sessions.reverse();
+    sessions
+}
+
+mod tests {
+    use super::*;
+
+    #[test]
+    fn splits_records_by_idle_gaps() {
+        let timed = Vec::from([(0, 5000), (1, 4900), (2, 1200), (3, 1000), (4, 100)]);
+
+        let got = split_by_gaps(timed, 600);
+
+        assert_eq!(got.len(), 3);
+        assert_eq!(got[0].records, Vec::from([1, 0]));
+        assert_eq!(got[1].records, Vec::from([3, 2]));
+        assert_eq!(got[1].first(), 3);
+        assert_eq!(got[1].started, 1000.into());
+        assert_eq!(got[1].ended, 1200.into());
+        assert_eq!(got[2].records, Vec::from([4]));
+    }
+
+    #[test]
+    fn does_not_split_without_gaps() {
+        let timed = Vec::from([(0, 300), (1, 200), (2, 100)]);
+
+        let got = split_by_gaps(timed, 600);
+
+        assert_eq!(got.len(), 1);
+        assert_eq!(got[0].first(), 2);
+    }
+}
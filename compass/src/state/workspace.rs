@@ -0,0 +1,10 @@
+use super::{Record, TrackList};
+
+pub const DEFAULT_LIST_NAME: &str = "default";
+
+/// A named list of records set aside while another one is in use
+#[derive(Debug)]
+pub struct Workspace {
+    pub name: String,
+    pub list: TrackList<Record>,
+}
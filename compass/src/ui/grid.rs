@@ -1,7 +1,7 @@
 use crate::{
     common_types::{CursorRange, Extmark, LazyRedraw},
-    config::{JumpKeymap, WindowGridSize},
-    state::{PlaceTypeRecord, Record},
+    config::{get_config, JumpKeymap, WindowGridSize},
+    state::Record,
     ui::{record_mark::create_hint_mark, tab::open_tab},
     Result,
 };
@@ -10,10 +10,10 @@ use anyhow::Context;
 use nvim_oxi::{
     api::{
         command, create_augroup, create_autocmd, create_buf, del_augroup_by_id, get_current_win,
-        get_option_value, open_win,
-        opts::{CreateAugroupOpts, CreateAutocmdOpts, OptionOpts, SetKeymapOpts},
+        get_option_value, notify, open_win,
+        opts::{CreateAugroupOpts, CreateAutocmdOpts, NotifyOpts, OptionOpts, SetKeymapOpts},
         set_current_win, set_option_value,
-        types::{Mode, WindowConfig, WindowRelativeTo},
+        types::{LogLevel, Mode, WindowConfig, WindowRelativeTo},
         Buffer, Window,
     },
     Function,
@@ -23,6 +23,8 @@ use nvim_oxi::{
 pub enum GridLayout {
     Open,
     Follow,
+    /// Choosing the record to move to the cursor the grid was opened from
+    Move,
 }
 
 pub fn open_grid<'a>(
@@ -46,14 +48,27 @@ pub fn open_grid<'a>(
     let mut record_iter = slice_record.iter();
 
     let (mut hidden_win, hidden_buf, old_guicursor) = open_hidden_float()?;
+    let mut uncombined_keys = Vec::<&str>::new();
     let mut create_hint =
         |record: &Record, ran: &CursorRange| -> Result<(Extmark, Option<Extmark>)> {
             let jump_keymap = jump_iter
                 .next()
                 .with_context(|| "no jump keymap to create a hint with")?;
 
-            set_buffer_jump_keymap(hidden_buf.clone(), jump_keymap, record, &layout, limit_win)?;
-            create_hint_mark(record.buf.clone(), ran, jump_keymap.follow.as_str(), layout)
+            uncombined_keys.extend(set_buffer_jump_keymap(
+                hidden_buf.clone(),
+                jump_keymap,
+                record,
+                &layout,
+                limit_win,
+            )?);
+            create_hint_mark(
+                record.buf.clone(),
+                ran,
+                jump_keymap.follow.as_str(),
+                record.note.as_deref(),
+                layout,
+            )
         };
 
     for i in 0..first_len_half {
@@ -97,6 +112,18 @@ pub fn open_grid<'a>(
         result.push((win, record.buf.clone(), hint));
     }
 
+    if !uncombined_keys.is_empty() {
+        let _ = notify(
+            &format!(
+                "compass: can not delete with the jump keys {}, only single characters can be combined with `{}`",
+                uncombined_keys.join(", "),
+                get_config().picker.delete_modifier
+            ),
+            LogLevel::Warn,
+            &NotifyOpts::builder().build(),
+        );
+    }
+
     set_current_win(&hidden_win)?;
     hidden_win.set_buf(&hidden_buf)?;
 
@@ -191,61 +218,79 @@ fn open_hidden_float() -> Result<(Window, Buffer, String)> {
     Ok((win, buf, old_guicursor))
 }
 
-fn set_buffer_jump_keymap(
+fn set_buffer_jump_keymap<'a>(
     mut buf: Buffer,
-    JumpKeymap { follow, immediate }: &JumpKeymap,
+    jump_keymap: &'a JumpKeymap,
     record: &Record,
     layout: &GridLayout,
     limit_win: WindowGridSize,
-) -> Result<()> {
-    fn get_goto_string(record: &Record) -> String {
-        match record.place_type {
-            PlaceTypeRecord::Change(_) => format!(
-                r#"time={{"buf":{},"timestamp":{}}}"#,
-                record.buf.handle(),
-                record.frecency.latest_timestamp()
-            ),
-        }
+) -> Result<Option<&'a str>> {
+    let (keymaps, uncombined) = hint_keymaps(jump_keymap, record, layout, limit_win);
+    for (lhs, rhs) in keymaps {
+        buf.set_keymap(
+            Mode::Normal,
+            lhs.as_str(),
+            rhs.as_str(),
+            &SetKeymapOpts::builder()
+                .noremap(true)
+                .nowait(true)
+                .silent(true)
+                .build(),
+        )?;
     }
 
-    buf.set_keymap(
-        Mode::Normal,
-        immediate.as_str(),
-        format!(
-            r#":tabclose<CR>:Compass goto absolute {}<CR>"#,
-            get_goto_string(record)
-        )
-        .as_str(),
-        &SetKeymapOpts::builder()
-            .noremap(true)
-            .nowait(true)
-            .silent(true)
-            .build(),
-    )?;
+    Ok(uncombined)
+}
 
-    buf.set_keymap(
-        Mode::Normal,
-        follow.as_str(),
-        match layout {
-            GridLayout::Open => format!(
-                r#":tabclose<CR>:Compass follow buf target={} max_windows={}<CR>"#,
+/// Left and right hand sides of the mappings of the hint, along with the key
+/// that was left without the delete mapping as the modifier can not be put on it
+fn hint_keymaps<'a>(
+    JumpKeymap { follow, immediate }: &'a JumpKeymap,
+    record: &Record,
+    layout: &GridLayout,
+    limit_win: WindowGridSize,
+) -> (Vec<(String, String)>, Option<&'a str>) {
+    let goto = format!(":tabclose<CR>:Compass goto absolute id={}<CR>", record.id);
+    let r#move = format!(":tabclose<CR>:Compass move absolute id={}<CR>", record.id);
+
+    let (rhs_immediate, rhs_follow) = match layout {
+        GridLayout::Open => (
+            goto,
+            format!(
+                ":tabclose<CR>:Compass follow buf target={} max_windows={}<CR>",
                 record.buf.handle(),
                 Into::<i32>::into(limit_win),
             ),
-            GridLayout::Follow => format!(
-                r#":tabclose<CR>:Compass goto absolute {}<CR>"#,
-                get_goto_string(record)
-            ),
+        ),
+        GridLayout::Follow => (goto.clone(), goto),
+        GridLayout::Move => (r#move.clone(), r#move),
+    };
+    let mut keymaps = Vec::from([
+        (immediate.clone(), rhs_immediate),
+        (follow.clone(), rhs_follow),
+    ]);
+
+    // Only the keys that stand for the single record the hint is shown at
+    let delete_key = match layout {
+        GridLayout::Open => immediate,
+        GridLayout::Follow => follow,
+        GridLayout::Move => return (keymaps, None),
+    };
+    let mut delete_chars = delete_key.chars();
+    match (delete_chars.next(), delete_chars.next()) {
+        // Unlike on `<CR>` or a sequence of keys
+        (Some(key), None) if key != '<' && !key.is_whitespace() => {
+            keymaps.push((
+                format!("<{}-{}>", get_config().picker.delete_modifier, key),
+                format!(
+                    ":tabclose<CR>:Compass pop absolute id={} jump=false<CR>",
+                    record.id
+                ),
+            ));
+            (keymaps, None)
         }
-        .as_str(),
-        &SetKeymapOpts::builder()
-            .noremap(true)
-            .nowait(true)
-            .silent(true)
-            .build(),
-    )?;
-
-    Ok(())
+        _ => (keymaps, Some(delete_key)),
+    }
 }
 
 fn new_split_config() -> (WindowConfig, WindowConfig) {
@@ -262,3 +307,69 @@ fn new_split_config() -> (WindowConfig, WindowConfig) {
             .build(),
     )
 }
+
+mod tests {
+    use super::*;
+
+    use crate::state::PlaceTypeRecord;
+
+    #[test]
+    fn maps_delete_on_the_key_of_a_single_record() {
+        let record = Record::new_unloaded(1.into(), PlaceTypeRecord::Jump, (1, 0).into());
+        let pop = format!(
+            ":tabclose<CR>:Compass pop absolute id={} jump=false<CR>",
+            record.id
+        );
+        let jump_keymap = JumpKeymap {
+            follow: "j".to_owned(),
+            immediate: "J".to_owned(),
+        };
+
+        let (open, uncombined) = hint_keymaps(
+            &jump_keymap,
+            &record,
+            &GridLayout::Open,
+            WindowGridSize::default(),
+        );
+        assert_eq!(uncombined, None);
+        assert_eq!(open.len(), 3);
+        assert_eq!(open[2], ("<M-J>".to_owned(), pop.clone()));
+
+        let (follow, uncombined) = hint_keymaps(
+            &jump_keymap,
+            &record,
+            &GridLayout::Follow,
+            WindowGridSize::default(),
+        );
+        assert_eq!(uncombined, None);
+        assert_eq!(follow[2], ("<M-j>".to_owned(), pop));
+
+        let (r#move, uncombined) = hint_keymaps(
+            &jump_keymap,
+            &record,
+            &GridLayout::Move,
+            WindowGridSize::default(),
+        );
+        assert_eq!(uncombined, None);
+        assert_eq!(r#move.len(), 2);
+    }
+
+    #[test]
+    fn reports_keys_not_combinable_with_delete_modifier() {
+        let record = Record::new_unloaded(1.into(), PlaceTypeRecord::Jump, (1, 0).into());
+
+        let jump_keymap = JumpKeymap {
+            follow: "j".to_owned(),
+            immediate: "<CR>".to_owned(),
+        };
+        let (keymaps, uncombined) = hint_keymaps(
+            &jump_keymap,
+            &record,
+            &GridLayout::Open,
+            WindowGridSize::default(),
+        );
+
+        assert_eq!(keymaps.len(), 2);
+        assert_eq!(uncombined, Some("<CR>"));
+    }
+}
@@ -21,6 +21,29 @@ pub enum RecordMarkTime {
     FutureClose,
 }
 
+/// Everything about a record that affects the way its mark is rendered
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Hash)]
+pub struct RecordMarkStyle<'a> {
+    pub time: RecordMarkTime,
+    pub stale: bool,
+    pub pinned: bool,
+    pub note: Option<&'a str>,
+    /// Number of the slot the record is bound to, shown instead of the sign
+    pub slot: Option<u8>,
+}
+
+impl From<RecordMarkTime> for RecordMarkStyle<'_> {
+    fn from(time: RecordMarkTime) -> Self {
+        Self {
+            time,
+            stale: false,
+            pinned: false,
+            note: None,
+            slot: None,
+        }
+    }
+}
+
 pub fn recreate_mark_time(i: usize, pos: Option<usize>) -> RecordMarkTime {
     match pos {
         Some(p) => {
@@ -44,17 +67,23 @@ pub fn recreate_mark_time(i: usize, pos: Option<usize>) -> RecordMarkTime {
     }
 }
 
-impl From<RecordMarkTime> for RecordHighlightNames {
-    fn from(value: RecordMarkTime) -> Self {
-        RecordHighlightList::record_hl_names(value)
+impl From<RecordMarkStyle<'_>> for RecordHighlightNames {
+    fn from(value: RecordMarkStyle) -> Self {
+        match value.stale {
+            true => RecordHighlightList::stale_hl_names(),
+            false => RecordHighlightList::record_hl_names(value.time),
+        }
     }
 }
 
-impl From<RecordMarkTime> for &SignText {
-    fn from(value: RecordMarkTime) -> Self {
+impl From<RecordMarkStyle<'_>> for &SignText {
+    fn from(value: RecordMarkStyle) -> Self {
         let signs = &get_config().marks.signs;
+        if value.pinned {
+            return &signs.pinned;
+        }
 
-        match value {
+        match value.time {
             RecordMarkTime::Past => &signs.past,
             RecordMarkTime::PastClose => &signs.close_past,
             RecordMarkTime::Future => &signs.future,
@@ -120,12 +149,12 @@ fn get_non_blank_pos(buf: Buffer, &CursorRange { line, col }: &CursorRange) -> C
 pub fn create_record_mark(
     mut buf: Buffer,
     ran: &CursorRange,
-    time: RecordMarkTime,
+    style: RecordMarkStyle,
 ) -> Result<Extmark> {
     let ran = get_non_blank_pos(buf.clone(), ran);
 
     let set_opts =
-        &basic_mark_builder(&mut SetExtmarkOpts::builder(), ran.line, ran.col, time).build();
+        &basic_mark_builder(&mut SetExtmarkOpts::builder(), ran.line, ran.col, style).build();
 
     buf.set_extmark(get_namespace().into(), ran.line, ran.col, set_opts)
         .map(|id| Extmark::new(id, Into::<CursorPosition>::into(&ran)))
@@ -136,11 +165,11 @@ pub fn update_record_mark(
     extmark: &Extmark,
     mut buf: Buffer,
     ran: &CursorRange,
-    time: RecordMarkTime,
+    style: RecordMarkStyle,
 ) -> Result<()> {
     let ran = get_non_blank_pos(buf.clone(), ran);
 
-    let set_opts = &basic_mark_builder(&mut SetExtmarkOpts::builder(), ran.line, ran.col, time)
+    let set_opts = &basic_mark_builder(&mut SetExtmarkOpts::builder(), ran.line, ran.col, style)
         .id(Into::<u32>::into(extmark))
         .build();
 
@@ -149,18 +178,29 @@ pub fn update_record_mark(
         .map_err(Into::into)
 }
 
-fn basic_mark_builder(
-    builder: &mut SetExtmarkOptsBuilder,
+fn basic_mark_builder<'a>(
+    builder: &'a mut SetExtmarkOptsBuilder,
     line: usize,
     col: usize,
-    time: RecordMarkTime,
-) -> &mut SetExtmarkOptsBuilder {
-    let hl: RecordHighlightNames = time.into();
+    style: RecordMarkStyle,
+) -> &'a mut SetExtmarkOptsBuilder {
+    let hl: RecordHighlightNames = style.into();
+    let slot_sign = style.slot.map(|n| n.to_string());
+    if let Some(note) = style.note {
+        builder
+            .virt_text([(note, RecordHighlightList::note_hl_name())])
+            .virt_text_pos(ExtmarkVirtTextPosition::Eol);
+    }
+
     builder
         .hl_mode(ExtmarkHlMode::Combine)
         .hl_group(hl.mark)
         .sign_hl_group(hl.sign)
-        .sign_text(Into::<&SignText>::into(time))
+        .sign_text(
+            slot_sign
+                .as_deref()
+                .unwrap_or(Into::<&SignText>::into(style)),
+        )
         .end_row(line)
         .end_col(col + 1)
         // Make sure to hide the extmark when it is deleted to avoid a blink
@@ -179,6 +219,7 @@ pub fn create_hint_mark(
     mut buf: Buffer,
     &CursorRange { line, col }: &CursorRange,
     name: &str,
+    note: Option<&str>,
     typ: GridLayout,
 ) -> Result<(Extmark, Option<Extmark>)> {
     let hl = HintHighlightList::hint_hl_names(typ);
@@ -201,17 +242,27 @@ pub fn create_hint_mark(
 
     let path = {
         let filename = &get_config().picker.filename;
-        match filename.enable {
-            true => Some(
+        let mut chunks = Vec::new();
+        if filename.enable {
+            chunks.push((
+                truncate_path(&buf.get_name()?, filename.depth)
+                    .to_string_lossy()
+                    .into_owned(),
+                hl.path,
+            ));
+        }
+        if let Some(note) = note {
+            chunks.push((note.to_owned(), RecordHighlightList::note_hl_name()));
+        }
+
+        match chunks.is_empty() {
+            false => Some(
                 buf.set_extmark(
                     get_namespace().into(),
                     line,
                     0,
                     &SetExtmarkOpts::builder()
-                        .virt_text([(
-                            truncate_path(&buf.get_name()?, filename.depth).as_path(),
-                            hl.path,
-                        )])
+                        .virt_text(chunks)
                         .virt_text_pos(ExtmarkVirtTextPosition::Eol)
                         .virt_text_hide(false)
                         .build(),
@@ -220,14 +271,14 @@ pub fn create_hint_mark(
                 .map_err(Into::<Error>::into)?
                 .map_err(Into::<Error>::into)?,
             ),
-            false => None,
+            true => None,
         }
     };
 
     Ok((label, path))
 }
 
-fn truncate_path(path: &Path, depth: usize) -> PathBuf {
+pub fn truncate_path(path: &Path, depth: usize) -> PathBuf {
     let mut components = path
         .components()
         .rev()
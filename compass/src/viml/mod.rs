@@ -1,2 +1,5 @@
 mod compass_args;
 pub use compass_args::CompassArgs;
+
+mod split_args;
+pub use split_args::split_args;
@@ -0,0 +1,60 @@
+/// Splits raw user command arguments on whitespace,
+/// keeping the double quoted value of a `key="some value"` argument as a single word
+pub fn split_args(args: &str) -> Vec<String> {
+    let mut words = Vec::new();
+    let mut word = String::new();
+    let mut quoted = false;
+
+    let mut chars = args.chars().peekable();
+    while let Some(c) = chars.next() {
+        match c {
+            '\\' if quoted && chars.peek().is_some_and(|&n| n == '"' || n == '\\') => {
+                // We know it is Some because of the guard
+                word.push(chars.next().unwrap());
+            }
+            '"' if quoted => quoted = false,
+            // Only the value right after the key is allowed to be quoted,
+            // so that json-like arguments are passed through untouched
+            '"' if word.find('=').is_some_and(|i| i + 1 == word.len()) => quoted = true,
+            c if c.is_whitespace() && !quoted => {
+                if !word.is_empty() {
+                    words.push(std::mem::take(&mut word));
+                }
+            }
+            c => word.push(c),
+        }
+    }
+    if !word.is_empty() {
+        words.push(word);
+    }
+
+    words
+}
+
+mod tests {
+    use super::*;
+
+    #[test]
+    fn can_split_plain_args() {
+        assert_eq!(
+            split_args("  goto relative   direction=back "),
+            vec!["goto", "relative", "direction=back"]
+        );
+    }
+
+    #[test]
+    fn can_split_quoted_value() {
+        assert_eq!(
+            split_args(r#"place change note="check \"null\" case" other=1"#),
+            vec!["place", "change", r#"note=check "null" case"#, "other=1"]
+        );
+    }
+
+    #[test]
+    fn keeps_json_values_intact() {
+        assert_eq!(
+            split_args(r#"goto absolute tick={"buf":1,"tick":42}"#),
+            vec!["goto", "absolute", r#"tick={"buf":1,"tick":42}"#]
+        );
+    }
+}
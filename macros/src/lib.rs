@@ -39,6 +39,32 @@ pub fn derive_deserializing_from_lua(input: TokenStream) -> TokenStream {
     .into()
 }
 
+/// Unlike `FromLua` bad input is an error rather than a fallback to the defaults,
+/// for the options of destructive functions that have none
+#[proc_macro_derive(FromLuaRequired)]
+pub fn derive_deserializing_from_lua_required(input: TokenStream) -> TokenStream {
+    let DeriveInput { ident, .. } = parse_macro_input!(input as DeriveInput);
+
+    quote! {
+        use nvim_oxi::conversion::FromObject;
+
+        impl nvim_oxi::conversion::FromObject for #ident {
+            fn from_object(obj: nvim_oxi::Object) -> core::result::Result<Self, nvim_oxi::conversion::Error> {
+                use serde::Deserialize;
+                Self::deserialize(nvim_oxi::serde::Deserializer::new(obj)).map_err(nvim_oxi::conversion::Error::from)
+            }
+        }
+
+        impl nvim_oxi::lua::Poppable for #ident {
+            unsafe fn pop(lstate: *mut nvim_oxi::lua::ffi::State) -> core::result::Result<Self, nvim_oxi::lua::Error> {
+                let obj = nvim_oxi::Object::pop(lstate)?;
+                Self::from_object(obj).map_err(|e| nvim_oxi::lua::Error::RuntimeError(e.to_string()))
+            }
+        }
+    }
+    .into()
+}
+
 #[proc_macro_derive(ToLua)]
 pub fn derive_serializing_to_lua(input: TokenStream) -> TokenStream {
     let DeriveInput { ident, .. } = parse_macro_input!(input as DeriveInput);